@@ -55,6 +55,16 @@ cargo run --locked --example custom
 cargo run --locked --example wrap_array_display_hex_trait
 cargo run --locked --example wrap_array_fmt_traits
 
+# Bench if told to, only works with non-stable toolchain (nightly, beta).
+if [ "$DO_BENCH" = true ]
+then
+    if [ "$NIGHTLY" = false ]; then
+        echo "DO_BENCH requires a nightly toolchain (consider using RUSTUP_TOOLCHAIN)"
+        exit 1
+    fi
+    RUSTFLAGS='--cfg=bench' cargo bench
+fi
+
 # Build the docs if told to (this only works with the nightly toolchain)
 if [ "$DO_DOCSRS" = true ]; then
     RUSTDOCFLAGS="--cfg docsrs -D warnings -D rustdoc::broken-intra-doc-links" cargo +nightly doc --all-features
@@ -5,10 +5,10 @@
 # shellcheck disable=SC2034
 
 # Test all these features with "std" enabled.
-FEATURES_WITH_STD="serde"
+FEATURES_WITH_STD="serde bytes smallvec"
 
 # Test all these features without "std" or "alloc" enabled.
-FEATURES_WITHOUT_STD="alloc serde"
+FEATURES_WITHOUT_STD="alloc serde bytes smallvec"
 
 # Run these examples.
 EXAMPLES="hexy:std custom:std wrap_array_display_hex_trait:std wrap_array_fmt_traits:std serde:std,serde"
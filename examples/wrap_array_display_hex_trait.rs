@@ -60,7 +60,7 @@ impl FromHex for Wrap {
 //     fn hex_reserve_suggestion(self) -> usize { self.0.as_ref().hex_reserve_suggestion() }
 // }
 impl<'a> DisplayHex for &'a Wrap {
-    type Display = DisplayArray<'a, 64>;
+    type Display = DisplayArray<'a, 32>;
     fn as_hex(self) -> Self::Display { self.0.as_hex() }
     fn hex_reserve_suggestion(self) -> usize { 64 }
 }
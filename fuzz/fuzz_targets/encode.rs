@@ -27,6 +27,8 @@ impl fmt::UpperHex for Hexy<'_> {
 }
 
 fn do_test(data: &[u8]) {
+    assert_eq!(hex::round_trip::encode_then_decode(data), data);
+
     let hexy = Hexy { data };
 
     let lower = format!("{:x}", hexy);
@@ -9,6 +9,9 @@ fn do_test(data: &[u8]) {
             let got = format!("{:x}", hexy.as_hex());
             assert_eq!(got, s.to_lowercase());
         }
+        if let Some(got) = hex::round_trip::decode_then_encode(s) {
+            assert_eq!(got, s.to_ascii_lowercase());
+        }
     }
 }
 
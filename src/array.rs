@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A fixed size byte array that displays and parses as hex.
+
+use core::convert::TryFrom;
+use core::{array, fmt, str};
+
+use crate::display::DisplayHex;
+use crate::error::HexToArrayError;
+use crate::parse::FromHex;
+
+/// A fixed size byte array that displays and parses as hex.
+///
+/// This is a batteries-included alternative to wrapping an array in a newtype and implementing
+/// the formatting traits using [`impl_fmt_traits`](crate::impl_fmt_traits).
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::HexArray;
+///
+/// let a = "deadbeef".parse::<HexArray<4>>().expect("valid length and valid hex digits");
+/// assert_eq!(a.to_byte_array(), [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(format!("{}", a), "deadbeef");
+/// assert_eq!(format!("{:X}", a), "DEADBEEF");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexArray<const N: usize>([u8; N]);
+
+impl<const N: usize> HexArray<N> {
+    /// Constructs a `HexArray` from a byte array.
+    #[inline]
+    pub const fn from_byte_array(bytes: [u8; N]) -> Self { Self(bytes) }
+
+    /// Returns a reference to the inner byte array.
+    #[inline]
+    pub const fn as_byte_array(&self) -> &[u8; N] { &self.0 }
+
+    /// Returns the inner byte array.
+    #[inline]
+    pub const fn to_byte_array(self) -> [u8; N] { self.0 }
+}
+
+impl<const N: usize> FromHex for HexArray<N> {
+    type Error = HexToArrayError;
+
+    #[inline]
+    fn from_hex(s: &str) -> Result<Self, Self::Error> { Ok(Self(FromHex::from_hex(s)?)) }
+
+    #[inline]
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        Ok(Self(FromHex::from_hex_prefixed(s)?))
+    }
+}
+
+impl<const N: usize> str::FromStr for HexArray<N> {
+    type Err = HexToArrayError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Self::from_hex(s) }
+}
+
+impl<const N: usize> fmt::Display for HexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<const N: usize> fmt::Debug for HexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl<const N: usize> fmt::LowerHex for HexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(&self.0.as_hex(), f) }
+}
+
+impl<const N: usize> fmt::UpperHex for HexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::UpperHex::fmt(&self.0.as_hex(), f) }
+}
+
+impl<const N: usize> AsRef<[u8]> for HexArray<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl<const N: usize> From<[u8; N]> for HexArray<N> {
+    #[inline]
+    fn from(bytes: [u8; N]) -> Self { Self(bytes) }
+}
+
+impl<const N: usize> From<HexArray<N>> for [u8; N] {
+    #[inline]
+    fn from(array: HexArray<N>) -> Self { array.0 }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for HexArray<N> {
+    type Error = array::TryFromSliceError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { Ok(Self(<[u8; N]>::try_from(bytes)?)) }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for HexArray<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::hex_array::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for HexArray<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        crate::serde::hex_array::deserialize(d).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{InvalidCharError, InvalidLengthError};
+
+    #[test]
+    fn parse() {
+        let a = "deadbeef".parse::<HexArray<4>>().unwrap();
+        assert_eq!(a.to_byte_array(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(HexArray::<4>::from_hex("DEADBEEF"), Ok(a));
+
+        assert_eq!("deadbe".parse::<HexArray<4>>(), Err(InvalidLengthError::new(6, 8).into()));
+        assert_eq!(
+            "deadbeeg".parse::<HexArray<4>>(),
+            Err(InvalidCharError { invalid: b'g', pos: 7 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn display() {
+        let a = HexArray::from([0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(format!("{}", a), "deadbeef");
+        assert_eq!(format!("{:?}", a), "deadbeef");
+        assert_eq!(format!("{:x}", a), "deadbeef");
+        assert_eq!(format!("{:X}", a), "DEADBEEF");
+        assert_eq!(format!("{:#}", a), "0xdeadbeef");
+        assert_eq!(format!("{:.4}", a), "dead");
+    }
+
+    #[test]
+    fn conversions() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        let a = HexArray::from_byte_array(bytes);
+        assert_eq!(a.as_byte_array(), &bytes);
+        assert_eq!(a.as_ref(), &bytes[..]);
+        assert_eq!(<[u8; 4]>::from(a), bytes);
+        assert_eq!(HexArray::<4>::try_from(&bytes[..]).unwrap(), a);
+        assert!(HexArray::<3>::try_from(&bytes[..]).is_err());
+    }
+}
@@ -7,29 +7,149 @@
 //! The main type in this module is [`BufEncoder`] which provides buffered hex encoding.
 //! `BufEncoder` is faster than the usual `write!(f, "{02x}", b)?` in a for loop because it reduces
 //! dynamic dispatch and decreases the number of allocations if a `String` is being created.
+//!
+//! `BufEncoder` is backed by a stack buffer, [`GenericBufEncoder`] can be used with any [`HexBuf`]
+//! e.g., a growable `String` to encode large inputs in one go.
 
 use core::borrow::Borrow;
+use core::fmt;
 
 use arrayvec::ArrayString;
 
 use super::{Case, Table};
+#[cfg(feature = "alloc")]
+use crate::alloc::string::String;
+use crate::error::{CapacityError, HexToBytesError, InvalidCharError, OddLengthStringError};
 
-/// Hex-encodes bytes into the provided buffer.
+/// A string buffer that a [`GenericBufEncoder`] writes hex into.
+///
+/// Implemented for [`ArrayString`] which has a fixed capacity and, with the `alloc` feature, for
+/// `String` which grows as needed.
+pub trait HexBuf {
+    /// Appends `s` to the buffer.
+    ///
+    /// The encoder checks [`remaining_capacity`](Self::remaining_capacity) before appending,
+    /// implementations may panic if `s` doesn't fit.
+    fn push_str(&mut self, s: &str);
+
+    /// Returns how many more bytes can be appended to the buffer.
+    fn remaining_capacity(&self) -> usize;
+
+    /// Returns the contents of the buffer.
+    fn as_str(&self) -> &str;
+
+    /// Removes all contents of the buffer.
+    fn clear(&mut self);
+}
+
+impl<const CAP: usize> HexBuf for ArrayString<CAP> {
+    #[inline]
+    #[track_caller]
+    fn push_str(&mut self, s: &str) { ArrayString::push_str(self, s) }
+
+    #[inline]
+    fn remaining_capacity(&self) -> usize { ArrayString::remaining_capacity(self) }
+
+    #[inline]
+    fn as_str(&self) -> &str { ArrayString::as_str(self) }
+
+    #[inline]
+    fn clear(&mut self) { ArrayString::clear(self) }
+}
+
+#[cfg(feature = "alloc")]
+impl HexBuf for String {
+    #[inline]
+    fn push_str(&mut self, s: &str) { String::push_str(self, s) }
+
+    /// A `String` can grow up to `isize::MAX` bytes.
+    #[inline]
+    fn remaining_capacity(&self) -> usize { isize::MAX as usize - self.len() }
+
+    #[inline]
+    fn as_str(&self) -> &str { String::as_str(self) }
+
+    #[inline]
+    fn clear(&mut self) { String::clear(self) }
+}
+
+/// Hex-encodes bytes into a stack buffer of `CAP` hex chars.
 ///
 /// This is an important building block for fast hex-encoding. Because string writing tools
 /// provided by `core::fmt` involve dynamic dispatch and don't allow reserving capacity in strings
 /// buffering the hex and then formatting it is significantly faster.
-pub struct BufEncoder<const CAP: usize> {
-    buf: ArrayString<CAP>,
+pub type BufEncoder<const CAP: usize> = GenericBufEncoder<ArrayString<CAP>>;
+
+/// Hex-encodes bytes into the provided [`HexBuf`].
+///
+/// This is [`BufEncoder`] with a configurable backing store, see its docs for details.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use hex_conservative::buf_encoder::GenericBufEncoder;
+/// use hex_conservative::Case;
+///
+/// let mut encoder = GenericBufEncoder::<String>::new(Case::Lower);
+/// encoder.put_bytes(&[0xab; 1000]);
+/// assert_eq!(encoder.into_buf(), "ab".repeat(1000));
+/// # }
+/// ```
+pub struct GenericBufEncoder<B: HexBuf> {
+    buf: B,
     table: &'static Table,
 }
 
 impl<const CAP: usize> BufEncoder<CAP> {
     const _CHECK_EVEN_CAPACITY: () = [(); 1][CAP % 2];
+}
+
+impl<B: HexBuf + Default> GenericBufEncoder<B> {
+    /// Creates an empty encoder that will encode bytes to hex characters in the given case.
+    #[inline]
+    pub fn new(case: Case) -> Self { Self::with_buf(B::default(), case) }
 
-    /// Creates an empty `BufEncoder` that will encode bytes to hex characters in the given case.
+    /// Encodes `bytes` as hex and writes them to `w`, reusing a single buffer.
+    ///
+    /// Long slices are encoded in chunks that fill the buffer (`CAP / 2` bytes for a
+    /// [`BufEncoder`]), each chunk is written to `w` before the buffer is reused for the next one.
+    /// This avoids allocating even if `bytes` is large.
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if the buffer has no capacity and `bytes` is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::buf_encoder::BufEncoder;
+    /// use hex_conservative::Case;
+    ///
+    /// let mut s = String::new();
+    /// BufEncoder::<4>::encode_slice_to(&[0xde, 0xad, 0xbe, 0xef, 0x01], Case::Lower, &mut s)
+    ///     .expect("writing to string doesn't fail");
+    /// assert_eq!(s, "deadbeef01");
+    /// ```
+    pub fn encode_slice_to<W: fmt::Write>(bytes: &[u8], case: Case, w: &mut W) -> fmt::Result {
+        Self::new(case).write_slice_to(bytes, w)
+    }
+}
+
+impl<B: HexBuf> GenericBufEncoder<B> {
+    /// Creates an encoder writing to `buf` that will encode bytes to hex characters in the given
+    /// case.
+    ///
+    /// Any existing contents of `buf` are cleared.
+    #[inline]
+    pub fn with_buf(mut buf: B, case: Case) -> Self {
+        buf.clear();
+        GenericBufEncoder { buf, table: case.table() }
+    }
+
+    /// Returns the underlying buffer.
     #[inline]
-    pub fn new(case: Case) -> Self { BufEncoder { buf: ArrayString::new(), table: case.table() } }
+    pub fn into_buf(self) -> B { self.buf }
 
     /// Encodes `byte` as hex and appends it to the buffer.
     ///
@@ -75,6 +195,32 @@ impl<const CAP: usize> BufEncoder<CAP> {
         }
     }
 
+    /// Encodes `bytes` as hex and appends them to the buffer if they all fit.
+    ///
+    /// This method works just like `put_bytes` but instead of panicking it returns an error and
+    /// leaves the buffer untouched. The required and available capacity in the error are counted
+    /// in bytes before encoding, same as [`space_remaining`](Self::space_remaining).
+    ///
+    /// # Errors
+    ///
+    /// If the bytes wouldn't fit the buffer.
+    #[inline]
+    pub fn put_bytes_exact<I>(&mut self, bytes: I) -> Result<(), CapacityError>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: Borrow<u8>,
+    {
+        let bytes = bytes.into_iter();
+        let required = bytes.len();
+        let available = self.space_remaining();
+        if required > available {
+            return Err(CapacityError { required, available });
+        }
+        self.put_bytes_inner(bytes);
+        Ok(())
+    }
+
     /// Encodes as many `bytes` as fit into the buffer as hex and return the remainder.
     ///
     /// This method works just like `put_bytes` but instead of panicking it returns the unwritten
@@ -88,13 +234,105 @@ impl<const CAP: usize> BufEncoder<CAP> {
         &bytes[to_write..]
     }
 
+    /// Encodes as many `bytes` as fit into the buffer as hex and returns how many were written.
+    ///
+    /// This is the same as [`put_bytes_min`](Self::put_bytes_min) but returns the number of
+    /// consumed bytes instead of the remainder, which is convenient when driving a chunked
+    /// writer by index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::buf_encoder::BufEncoder;
+    /// use hex_conservative::Case;
+    ///
+    /// let mut encoder = BufEncoder::<4>::new(Case::Lower);
+    /// assert_eq!(encoder.put_bytes_counted(&[0xde, 0xad, 0xbe]), 2);
+    /// assert_eq!(encoder.as_str(), "dead");
+    /// ```
+    #[must_use = "this may write only part of the input buffer"]
+    #[inline]
+    #[track_caller]
+    pub fn put_bytes_counted(&mut self, bytes: &[u8]) -> usize {
+        bytes.len() - self.put_bytes_min(bytes).len()
+    }
+
+    /// Appends already hex-encoded `hex` to the buffer without decoding it.
+    ///
+    /// Only digits in the case of this encoder are accepted (e.g. `"2a"` but not `"2A"` for a
+    /// lower-case encoder) so that the buffer never contains mixed case hex. The whole string is
+    /// validated before anything is appended, the buffer is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - If `hex` has an odd length.
+    /// - If `hex` contains a character that is not a hex digit in the encoder's case.
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if `hex` wouldn't fit the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::buf_encoder::BufEncoder;
+    /// use hex_conservative::Case;
+    ///
+    /// let mut encoder = BufEncoder::<8>::new(Case::Lower);
+    /// encoder.put_byte(0xde);
+    /// encoder.put_hex_str("adbe").expect("valid lower-case hex");
+    /// encoder.put_byte(0xef);
+    /// assert_eq!(encoder.as_str(), "deadbeef");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn put_hex_str(&mut self, hex: &str) -> Result<(), HexToBytesError> {
+        if hex.len() % 2 != 0 {
+            return Err(OddLengthStringError { len: hex.len() }.into());
+        }
+        if let Some(pos) = hex.bytes().position(|c| !self.table.contains(c)) {
+            return Err(InvalidCharError { invalid: hex.as_bytes()[pos], pos }.into());
+        }
+        assert!(hex.len() / 2 <= self.space_remaining());
+        self.buf.push_str(hex);
+        Ok(())
+    }
+
+    /// Encodes `bytes` in chunks using this buffer, writing each chunk to `w`.
+    ///
+    /// The buffer is cleared before and after use.
+    #[track_caller]
+    pub(crate) fn write_slice_to<W: fmt::Write + ?Sized>(
+        &mut self,
+        bytes: &[u8],
+        w: &mut W,
+    ) -> fmt::Result {
+        self.clear();
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        for chunk in bytes.chunks(self.space_remaining()) {
+            self.put_bytes(chunk);
+            let res = w.write_str(self.as_str());
+            self.clear();
+            res?;
+        }
+        Ok(())
+    }
+
     /// Returns true if no more bytes can be written into the buffer.
     #[inline]
-    pub fn is_full(&self) -> bool { self.buf.is_full() }
+    pub fn is_full(&self) -> bool { self.buf.remaining_capacity() == 0 }
 
     /// Returns the written bytes as a hex `str`.
     #[inline]
-    pub fn as_str(&self) -> &str { &self.buf }
+    pub fn as_str(&self) -> &str { self.buf.as_str() }
+
+    /// Returns the written hex digits as ASCII bytes.
+    ///
+    /// Handy for writing the hex to an `io::Write` sink.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { self.buf.as_str().as_bytes() }
 
     /// Resets the buffer to become empty.
     #[inline]
@@ -120,10 +358,24 @@ impl<const CAP: usize> BufEncoder<CAP> {
     }
 }
 
-impl<const CAP: usize> Default for BufEncoder<CAP> {
+impl<B: HexBuf + Default> Default for GenericBufEncoder<B> {
     fn default() -> Self { Self::new(Case::Lower) }
 }
 
+/// Encodes the written bytes as hex into the buffer.
+///
+/// `write` encodes as many bytes as fit and returns their count, `Ok(0)` once the buffer is full.
+/// `write_all` therefore fails with [`std::io::ErrorKind::WriteZero`] if the input doesn't fit,
+/// the bytes that fit are still encoded.
+#[cfg(feature = "std")]
+impl<B: HexBuf> std::io::Write for GenericBufEncoder<B> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> { Ok(self.put_bytes_counted(buf)) }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,6 +407,7 @@ mod tests {
         assert_eq!(encoder.space_remaining(), 1);
         encoder.put_byte(42);
         assert_eq!(encoder.as_str(), "2A");
+        assert_eq!(encoder.as_bytes(), b"2A");
         assert_eq!(encoder.space_remaining(), 0);
         assert!(encoder.is_full());
         encoder.clear();
@@ -227,6 +480,111 @@ mod tests {
         assert_eq!(encoder.as_str(), "2a");
     }
 
+    #[test]
+    fn put_bytes_counted() {
+        let mut encoder = BufEncoder::<4>::new(Case::Lower);
+        assert_eq!(encoder.put_bytes_counted(b""), 0);
+        assert_eq!(encoder.put_bytes_counted(b"*"), 1);
+        assert_eq!(encoder.put_bytes_counted(&[42, 255]), 1);
+        assert_eq!(encoder.as_str(), "2a2a");
+        assert_eq!(encoder.put_bytes_counted(&[255]), 0);
+    }
+
+    #[test]
+    fn put_bytes_exact() {
+        let mut encoder = BufEncoder::<4>::new(Case::Lower);
+        assert_eq!(encoder.put_bytes_exact(b""), Ok(()));
+        assert_eq!(encoder.put_bytes_exact(b"*"), Ok(()));
+        assert_eq!(encoder.as_str(), "2a");
+        assert_eq!(
+            encoder.put_bytes_exact(b"*\xff"),
+            Err(CapacityError { required: 2, available: 1 })
+        );
+        assert_eq!(encoder.as_str(), "2a");
+        assert_eq!(encoder.put_bytes_exact(b"\xff"), Ok(()));
+        assert_eq!(encoder.as_str(), "2aff");
+        assert_eq!(encoder.put_bytes_exact(b""), Ok(()));
+    }
+
+    #[test]
+    fn put_hex_str() {
+        let mut encoder = BufEncoder::<6>::new(Case::Lower);
+        assert_eq!(encoder.put_hex_str(""), Ok(()));
+        encoder.put_byte(0x2a);
+        assert_eq!(encoder.put_hex_str("ff0a"), Ok(()));
+        assert_eq!(encoder.as_str(), "2aff0a");
+        assert!(encoder.is_full());
+
+        encoder.clear();
+        assert_eq!(encoder.put_hex_str("abc"), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(
+            encoder.put_hex_str("aBcd"),
+            Err(InvalidCharError { invalid: b'B', pos: 1 }.into())
+        );
+        assert_eq!(encoder.as_str(), "");
+
+        let mut encoder = BufEncoder::<4>::new(Case::Upper);
+        assert_eq!(encoder.put_hex_str("AB09"), Ok(()));
+        encoder.clear();
+        assert_eq!(
+            encoder.put_hex_str("0a"),
+            Err(InvalidCharError { invalid: b'a', pos: 1 }.into())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn put_hex_str_overflow() {
+        let mut encoder = BufEncoder::<2>::new(Case::Lower);
+        let _ = encoder.put_hex_str("abcd");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_slice_to() {
+        use crate::alloc::string::String;
+
+        let bytes = [0xde, 0xad, 0xbe, 0xef, 0x01];
+        let mut s = String::new();
+        BufEncoder::<2>::encode_slice_to(&bytes, Case::Lower, &mut s).unwrap();
+        assert_eq!(s, "deadbeef01");
+
+        s.clear();
+        BufEncoder::<4>::encode_slice_to(&bytes, Case::Upper, &mut s).unwrap();
+        assert_eq!(s, "DEADBEEF01");
+
+        s.clear();
+        BufEncoder::<16>::encode_slice_to(&bytes, Case::Lower, &mut s).unwrap();
+        assert_eq!(s, "deadbeef01");
+
+        s.clear();
+        BufEncoder::<0>::encode_slice_to(&[], Case::Lower, &mut s).unwrap();
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn string_backed() {
+        use crate::alloc::string::String;
+
+        let mut encoder = GenericBufEncoder::with_buf(String::from("junk"), Case::Upper);
+        assert_eq!(encoder.as_str(), "");
+        assert!(!encoder.is_full());
+        encoder.put_bytes([0x2a; 2048]);
+        assert_eq!(encoder.put_bytes_exact(b"\xff"), Ok(()));
+        assert_eq!(encoder.put_hex_str("AB"), Ok(()));
+        assert!(encoder.space_remaining() > 0);
+
+        let s = encoder.into_buf();
+        assert_eq!(s.len(), 4100);
+        assert!(s.starts_with("2A2A"));
+        assert!(s.ends_with("2AFFAB"));
+
+        let mut s = String::new();
+        GenericBufEncoder::<String>::encode_slice_to(&[0xde, 0xad], Case::Lower, &mut s).unwrap();
+        assert_eq!(s, "dead");
+    }
+
     #[test]
     fn same_as_fmt() {
         use core::fmt::{self, Write};
@@ -273,4 +631,22 @@ mod tests {
             encoder.clear();
         }
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_write() {
+        use std::io::Write as _;
+
+        let mut encoder = BufEncoder::<6>::new(Case::Lower);
+        assert_eq!(encoder.write(&[0xde, 0xad]).unwrap(), 2);
+        assert_eq!(encoder.write(&[0xbe, 0xef]).unwrap(), 1);
+        assert_eq!(encoder.write(&[0xef]).unwrap(), 0);
+        assert_eq!(encoder.as_str(), "deadbe");
+
+        encoder.clear();
+        encoder.write_all(&[0xab, 0xcd]).unwrap();
+        let err = encoder.write_all(&[0xef, 0x01]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+        assert_eq!(encoder.as_str(), "abcdef");
+    }
 }
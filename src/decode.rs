@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Decoding of hex in formats other than plain pairs of hex digits.
+//!
+//! These are shorthands for common [`DecodeOptions`] and for prefixed input. Use
+//! [`FromHex`] to decode plain hex.
+
+#[cfg(feature = "alloc")]
+use crate::error::{
+    DecodeOptionsError, HexToBytesStrictError, SingleCaseError, UnexpectedPrefixError,
+};
+use crate::{decode_prefixed, FromHex, HexToArrayError};
+#[cfg(feature = "alloc")]
+use crate::{Case, DecodeOptions, HexToBytesError, InvalidCharError, OddLengthStringError};
+
+/// Decodes a hex string with an optional `0x` or `0X` prefix into a fixed size array.
+///
+/// A single leading prefix is stripped before decoding, the length is checked after stripping.
+/// Positions and lengths in errors refer to `hex` including the prefix.
+///
+/// # Errors
+///
+/// - If the length of `hex` without the prefix is not exactly `N * 2`.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode;
+///
+/// assert_eq!(decode::to_array_prefixed::<2>("0xdead"), Ok([0xde, 0xad]));
+/// assert_eq!(decode::to_array_prefixed::<2>("dead"), Ok([0xde, 0xad]));
+/// ```
+pub fn to_array_prefixed<const N: usize>(hex: &str) -> Result<[u8; N], HexToArrayError> {
+    decode_prefixed(hex, <[u8; N]>::from_hex)
+}
+
+/// Decodes a hex string with an optional `0x` or `0X` prefix into a vector.
+///
+/// A single leading prefix is stripped before decoding, the length is checked after stripping.
+/// Positions and lengths in errors refer to `hex` including the prefix.
+///
+/// # Errors
+///
+/// - If the length of `hex` without the prefix is odd.
+/// - If `hex` contains a non-hexadecimal character.
+#[cfg(feature = "alloc")]
+pub fn to_vec_prefixed(hex: &str) -> Result<alloc::vec::Vec<u8>, HexToBytesError> {
+    decode_prefixed(hex, FromHex::from_hex)
+}
+
+/// Decodes a hex string that must not have a `0x` prefix into a vector.
+///
+/// This accepts the same input as decoding with [`FromHex`] but reports a prefixed input with a
+/// dedicated error instead of an invalid `x` character at position 1.
+///
+/// # Errors
+///
+/// - If `hex` starts with `0x` or `0X`, this is checked first.
+/// - If the length of `hex` is odd.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode;
+/// use hex_conservative::error::HexToBytesStrictError;
+///
+/// assert_eq!(decode::to_vec_strict("dead").expect("valid hex"), [0xde, 0xad]);
+/// let err = decode::to_vec_strict("0xdead").unwrap_err();
+/// assert!(matches!(err, HexToBytesStrictError::UnexpectedPrefix(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_vec_strict(hex: &str) -> Result<alloc::vec::Vec<u8>, HexToBytesStrictError> {
+    if let Some(prefix) = ["0x", "0X"].iter().find(|prefix| hex.starts_with(*prefix)) {
+        return Err(UnexpectedPrefixError { prefix }.into());
+    }
+    Ok(FromHex::from_hex(hex)?)
+}
+
+/// Decodes a hex string which must not mix lower and upper case digits into a vector.
+///
+/// Returns the decoded bytes together with the case of the input. Input consisting only of the
+/// digits `0-9` is valid in either case and reports [`Case::Lower`]. The input is checked and
+/// decoded in a single pass.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If `hex` contains a non-hexadecimal character.
+/// - If `hex` contains mixed case digits, the first digit whose case differs from the case of the
+///   preceding alphabetic digits is reported.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::error::SingleCaseError;
+/// use hex_conservative::{decode, Case};
+///
+/// let (bytes, case) = decode::to_vec_single_case("DEADBEEF").expect("valid hex");
+/// assert_eq!(bytes, [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(case, Case::Upper);
+///
+/// let err = decode::to_vec_single_case("DEADbeef").unwrap_err();
+/// assert!(matches!(err, SingleCaseError::MixedCase(e) if e.pos() == 4));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_vec_single_case(hex: &str) -> Result<(alloc::vec::Vec<u8>, Case), SingleCaseError> {
+    if hex.len() % 2 != 0 {
+        return Err(OddLengthStringError { len: hex.len() }.into());
+    }
+    match DecodeOptions::new().require_single_case(true).decode_to_vec_with_case(hex) {
+        Ok((ret, case)) => Ok((ret, case.unwrap_or_default())),
+        Err(DecodeOptionsError::InvalidChar(e)) => Err(e.into()),
+        // Not reachable since the length was checked above.
+        Err(DecodeOptionsError::OddDigitCount(_)) =>
+            Err(OddLengthStringError { len: hex.len() }.into()),
+        Err(DecodeOptionsError::MixedCase(e)) => Err(e.into()),
+    }
+}
+
+/// Decodes a hex string containing ASCII whitespace into a vector.
+///
+/// Spaces, tabs, newlines and carriage returns are skipped, which allows decoding formatted hex
+/// dumps directly.
+///
+/// # Errors
+///
+/// - If `hex` contains a character that is neither a hex digit nor whitespace, the position
+///   refers to the input string.
+/// - If `hex` contains an odd number of hex digits (whitespace is not counted).
+///
+/// This is the same as decoding with [`DecodeOptions::skip_whitespace`] so the error is a
+/// [`DecodeOptionsError`] but it is never [`DecodeOptionsError::MixedCase`].
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode;
+///
+/// let v = decode::to_vec_skip_whitespace("de ad\nbe ef").expect("valid hex");
+/// assert_eq!(v, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_vec_skip_whitespace(hex: &str) -> Result<alloc::vec::Vec<u8>, DecodeOptionsError> {
+    DecodeOptions::new().skip_whitespace(true).decode_to_vec(hex)
+}
+
+/// Decodes a hex string that may have odd length into a vector.
+///
+/// Even length input decodes the same as `Vec::from_hex`. Odd length input is treated as if a
+/// single `0` digit was added at the position given by `pad`, for example `"abc"` decodes as
+/// `[0x0a, 0xbc]` with [`NibblePad::Leading`] and as `[0xab, 0xc0]` with [`NibblePad::Trailing`].
+///
+/// This is meant for importing data from legacy tools that don't enforce even length, use
+/// `Vec::from_hex` if odd length input should be rejected. This is the same as decoding with
+/// [`DecodeOptions::pad_odd_length`].
+///
+/// # Errors
+///
+/// If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode::{self, NibblePad};
+///
+/// assert_eq!(decode::to_vec_lenient("abc", NibblePad::Leading).unwrap(), [0x0a, 0xbc]);
+/// assert_eq!(decode::to_vec_lenient("abc", NibblePad::Trailing).unwrap(), [0xab, 0xc0]);
+/// assert_eq!(decode::to_vec_lenient("abcd", NibblePad::Leading).unwrap(), [0xab, 0xcd]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_vec_lenient(hex: &str, pad: NibblePad) -> Result<alloc::vec::Vec<u8>, InvalidCharError> {
+    match DecodeOptions::new().pad_odd_length(Some(pad)).decode_to_vec(hex) {
+        Ok(ret) => Ok(ret),
+        Err(DecodeOptionsError::InvalidChar(e)) => Err(e),
+        // Not reachable since odd length is padded and mixed case is allowed.
+        Err(DecodeOptionsError::OddDigitCount(_)) | Err(DecodeOptionsError::MixedCase(_)) =>
+            unreachable!("only invalid characters are rejected"),
+    }
+}
+
+/// Where to add the missing zero digit when decoding odd length hex.
+///
+/// See [`to_vec_lenient`] and [`DecodeOptions::pad_odd_length`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NibblePad {
+    /// Pad at the front, the first digit becomes the low-order nibble of the first byte.
+    Leading,
+
+    /// Pad at the back, the last digit becomes the high-order nibble of the last byte.
+    Trailing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{InvalidCharError, InvalidLengthError};
+    #[cfg(feature = "alloc")]
+    use crate::error::{MixedCaseError, OddDigitCountError};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_odd_length() {
+        assert_eq!(to_vec_lenient("", NibblePad::Leading).unwrap(), [0u8; 0]);
+        assert_eq!(to_vec_lenient("", NibblePad::Trailing).unwrap(), [0u8; 0]);
+        assert_eq!(to_vec_lenient("a", NibblePad::Leading).unwrap(), [0x0a]);
+        assert_eq!(to_vec_lenient("a", NibblePad::Trailing).unwrap(), [0xa0]);
+        assert_eq!(to_vec_lenient("abc", NibblePad::Leading).unwrap(), [0x0a, 0xbc]);
+        assert_eq!(to_vec_lenient("abc", NibblePad::Trailing).unwrap(), [0xab, 0xc0]);
+        assert_eq!(to_vec_lenient("ABCD", NibblePad::Leading).unwrap(), [0xab, 0xcd]);
+        assert_eq!(to_vec_lenient("ABCD", NibblePad::Trailing).unwrap(), [0xab, 0xcd]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_odd_length_error() {
+        assert_eq!(
+            to_vec_lenient("abg", NibblePad::Leading),
+            Err(InvalidCharError { invalid: b'g', pos: 2 })
+        );
+        assert_eq!(
+            to_vec_lenient("gab", NibblePad::Trailing),
+            Err(InvalidCharError { invalid: b'g', pos: 0 })
+        );
+        assert_eq!(
+            to_vec_lenient("a«", NibblePad::Trailing),
+            Err(InvalidCharError { invalid: 0xc2, pos: 1 })
+        );
+    }
+
+    #[test]
+    fn to_array_with_prefix() {
+        assert_eq!(to_array_prefixed::<2>("0xdead"), Ok([0xde, 0xad]));
+        assert_eq!(to_array_prefixed::<2>("0Xdead"), Ok([0xde, 0xad]));
+        assert_eq!(to_array_prefixed::<2>("dead"), Ok([0xde, 0xad]));
+        assert_eq!(to_array_prefixed::<0>("0x"), Ok([]));
+        // Positions and lengths refer to the input including the prefix.
+        assert_eq!(
+            to_array_prefixed::<2>("0xdeadbe"),
+            Err(InvalidLengthError { invalid: 8, expected: 6, bytes: 2 }.into())
+        );
+        assert_eq!(to_array_prefixed::<2>("deadbe"), Err(InvalidLengthError::new(6, 4).into()));
+        assert_eq!(
+            to_array_prefixed::<3>("0x0xdead"),
+            Err(InvalidCharError { invalid: b'x', pos: 3 }.into())
+        );
+        assert_eq!(
+            to_array_prefixed::<2>("0Xdeag"),
+            Err(InvalidCharError { invalid: b'g', pos: 5 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_rejects_prefix() {
+        assert_eq!(to_vec_strict("dead"), Ok(vec![0xde, 0xad]));
+        assert_eq!(to_vec_strict(""), Ok(vec![]));
+        assert_eq!(to_vec_strict("0xdead"), Err(UnexpectedPrefixError { prefix: "0x" }.into()));
+        assert_eq!(to_vec_strict("0Xdea"), Err(UnexpectedPrefixError { prefix: "0X" }.into()));
+        assert_eq!(to_vec_strict("0x"), Err(UnexpectedPrefixError { prefix: "0x" }.into()));
+        assert_eq!(to_vec_strict("dea"), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(to_vec_strict("0g"), Err(InvalidCharError { invalid: b'g', pos: 1 }.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_single_case_detects_case() {
+        assert_eq!(to_vec_single_case(""), Ok((vec![], Case::Lower)));
+        assert_eq!(to_vec_single_case("0123"), Ok((vec![0x01, 0x23], Case::Lower)));
+        assert_eq!(to_vec_single_case("0a1b"), Ok((vec![0x0a, 0x1b], Case::Lower)));
+        assert_eq!(to_vec_single_case("0A1B"), Ok((vec![0x0a, 0x1b], Case::Upper)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_single_case_error() {
+        assert_eq!(
+            to_vec_single_case("00aB"),
+            Err(MixedCaseError { invalid: b'B', pos: 3 }.into())
+        );
+        assert_eq!(
+            to_vec_single_case("A0g0"),
+            Err(InvalidCharError { invalid: b'g', pos: 2 }.into())
+        );
+        assert_eq!(to_vec_single_case("aB0"), Err(OddLengthStringError { len: 3 }.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_with_prefix() {
+        assert_eq!(to_vec_prefixed("0xdead"), Ok(vec![0xde, 0xad]));
+        assert_eq!(to_vec_prefixed("0XDEAD"), Ok(vec![0xde, 0xad]));
+        assert_eq!(to_vec_prefixed("dead"), Ok(vec![0xde, 0xad]));
+        assert_eq!(to_vec_prefixed("0x"), Ok(vec![]));
+        assert_eq!(to_vec_prefixed("0xdea"), Err(OddLengthStringError { len: 5 }.into()));
+        assert_eq!(to_vec_prefixed("dea"), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(
+            to_vec_prefixed("0xdeag"),
+            Err(InvalidCharError { invalid: b'g', pos: 5 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_whitespace() {
+        let want = vec![0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(to_vec_skip_whitespace("deadbeef"), Ok(want.clone()));
+        assert_eq!(to_vec_skip_whitespace("de ad be ef"), Ok(want.clone()));
+        assert_eq!(to_vec_skip_whitespace(" d e\ta\r\nd beef\n"), Ok(want));
+        assert_eq!(to_vec_skip_whitespace(" \n"), Ok(vec![]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_vec_whitespace_error() {
+        assert_eq!(
+            to_vec_skip_whitespace("de ad bx ef"),
+            Err(InvalidCharError { invalid: b'x', pos: 7 }.into())
+        );
+        assert_eq!(
+            to_vec_skip_whitespace("de\x0cad"),
+            Err(InvalidCharError { invalid: 0x0c, pos: 2 }.into())
+        );
+        assert_eq!(
+            to_vec_skip_whitespace("de ad b"),
+            Err(OddDigitCountError { digits: 5, pos: 6 }.into())
+        );
+        assert_eq!(
+            to_vec_skip_whitespace("de ad b\n"),
+            Err(OddDigitCountError { digits: 5, pos: 6 }.into())
+        );
+        assert_eq!(
+            OddDigitCountError { digits: 5, pos: 6 }.to_string(),
+            "odd number of hex digits 5, the digit at pos 6 has no pair"
+        );
+    }
+}
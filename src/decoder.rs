@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Implements a stateful decoder for hex split across multiple chunks.
+
+use crate::alloc::vec::Vec;
+use crate::error::{InvalidCharError, OddLengthStringError};
+use crate::iter::hex_digit;
+
+/// Decodes hex that arrives in chunks, e.g. from a network stream.
+///
+/// A chunk may end in the middle of a byte, the pending digit is carried over to the next call to
+/// [`push_str`](Self::push_str). Upper, lower and mixed case digits are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::HexDecoder;
+///
+/// let mut decoder = HexDecoder::new();
+/// decoder.push_str("dea").expect("valid hex");
+/// decoder.push_str("dBEEF").expect("valid hex");
+/// assert_eq!(decoder.finish().expect("even length"), [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HexDecoder {
+    decoded: Vec<u8>,
+    /// The high nibble of a byte whose low nibble hasn't been pushed yet.
+    high: Option<u8>,
+    /// The number of hex digits pushed so far.
+    digits: usize,
+}
+
+impl HexDecoder {
+    /// Creates an empty decoder.
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Decodes `chunk` and appends the bytes to the ones decoded so far.
+    ///
+    /// # Errors
+    ///
+    /// If `chunk` contains a non-hexadecimal character. The position is counted from the start of
+    /// the first chunk. The decoder is left unchanged if an error is returned.
+    pub fn push_str(&mut self, chunk: &str) -> Result<(), InvalidCharError> {
+        let (decoded_len, high) = (self.decoded.len(), self.high);
+        self.decoded.reserve((chunk.len() + 1) / 2);
+        for (pos, c) in chunk.bytes().enumerate() {
+            let nibble = match hex_digit(c) {
+                Some(nibble) => nibble,
+                None => {
+                    // Roll back the bytes decoded from this chunk.
+                    self.decoded.truncate(decoded_len);
+                    self.high = high;
+                    return Err(InvalidCharError { invalid: c, pos: self.digits + pos });
+                }
+            };
+            match self.high.take() {
+                Some(high) => self.decoded.push((high << 4) | nibble),
+                None => self.high = Some(nibble),
+            }
+        }
+        self.digits += chunk.len();
+        Ok(())
+    }
+
+    /// Returns the bytes decoded so far, excluding a pending digit.
+    #[inline]
+    pub fn decoded(&self) -> &[u8] { &self.decoded }
+
+    /// Finishes decoding and returns all the decoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// If an odd number of hex digits was pushed in total.
+    pub fn finish(self) -> Result<Vec<u8>, OddLengthStringError> {
+        match self.high {
+            Some(_) => Err(OddLengthStringError { len: self.digits }),
+            None => Ok(self.decoded),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_across_chunks() {
+        let mut decoder = HexDecoder::new();
+        for chunk in ["", "d", "ead", "", "B", "eEf"] {
+            decoder.push_str(chunk).unwrap();
+        }
+        assert_eq!(decoder.decoded(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(decoder.finish().unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+
+        assert!(HexDecoder::new().finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_char() {
+        let mut decoder = HexDecoder::new();
+        decoder.push_str("abc").unwrap();
+        assert_eq!(decoder.push_str("d0g"), Err(InvalidCharError { invalid: b'g', pos: 5 }));
+        assert_eq!(decoder.decoded(), [0xab]);
+        assert_eq!(decoder.push_str("«"), Err(InvalidCharError { invalid: 0xc2, pos: 3 }));
+        // Failed chunks are not decoded.
+        decoder.push_str("d").unwrap();
+        assert_eq!(decoder.finish().unwrap(), [0xab, 0xcd]);
+    }
+
+    #[test]
+    fn odd_length() {
+        let mut decoder = HexDecoder::new();
+        decoder.push_str("abc").unwrap();
+        decoder.push_str("de").unwrap();
+        assert_eq!(decoder.decoded(), [0xab, 0xcd]);
+        assert_eq!(decoder.finish(), Err(OddLengthStringError { len: 5 }));
+    }
+}
@@ -26,14 +26,13 @@
 //!```
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::borrow::Borrow;
 use core::fmt;
 
-use super::Case;
-#[cfg(any(test, feature = "std"))]
-use super::Table;
+use super::{Case, Table};
 use crate::buf_encoder::BufEncoder;
+use crate::error::SliceLengthError;
 
 /// Extension trait for types that can be displayed as hex.
 ///
@@ -43,6 +42,13 @@ use crate::buf_encoder::BufEncoder;
 /// This trait should be generally implemented for references only. We would prefer to use GAT but
 /// that is beyond our MSRV. As a lint we require the `IsRef` trait which is implemented for all
 /// references.
+///
+/// There is no blanket implementation for `&T where T: AsRef<[u8]>` because it would overlap with
+/// the implementation for arrays (which display with [`DisplayArray`]) and with implementations
+/// in downstream crates. Other byte containers can be displayed using `bytes.as_ref().as_hex()`.
+///
+/// Mutable references can't implement this trait since they are not `Copy`. This only matters for
+/// generic code, method calls such as `mut_slice.as_hex()` reborrow automatically.
 pub trait DisplayHex: Copy + sealed::IsRef {
     /// The type providing [`fmt::Display`] implementation.
     ///
@@ -52,6 +58,21 @@ pub trait DisplayHex: Copy + sealed::IsRef {
     /// Display `Self` as a continuous sequence of ASCII hex chars.
     fn as_hex(self) -> Self::Display;
 
+    /// Display `Self` as a continuous sequence of upper-case ASCII hex chars.
+    ///
+    /// The `Display` implementation of the returned value uses upper-case, this is useful where
+    /// no format specifier can be given e.g., `serializer.collect_str(&bytes.as_hex_upper())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0xde, 0xad, 0xbe, 0xef];
+    /// assert_eq!(format!("{}", v.as_hex_upper()), "DEADBEEF");
+    /// ```
+    fn as_hex_upper(self) -> DisplayUpper<Self::Display> { DisplayUpper(self.as_hex()) }
+
     /// Create a lower-hex-encoded string.
     ///
     /// A shorthand for `to_hex_string(Case::Lower)`, so that `Case` doesn't need to be imported.
@@ -78,25 +99,89 @@ pub trait DisplayHex: Copy + sealed::IsRef {
         string
     }
 
+    /// Create a hex-encoded string with exactly the required capacity.
+    ///
+    /// Unlike [`to_hex_string`](Self::to_hex_string) this allocates the string using
+    /// [`hex_len`](Self::hex_len) up front, so it performs exactly one allocation (none for empty
+    /// input) for the implementations in this crate and never reallocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::{Case, DisplayHex};
+    ///
+    /// let s = [0xde, 0xad, 0xbe, 0xef].to_hex_string_exact(Case::Lower);
+    /// assert_eq!(s, "deadbeef");
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_hex_string_exact(self, case: Case) -> String {
+        let mut string = String::with_capacity(self.hex_len());
+        write_hex(self, case, &mut string);
+        string
+    }
+
     /// Appends hex-encoded content to an existing `String`.
     ///
     /// This may be faster than `write!(string, "{:x}", self.as_hex())` because it uses
     /// `hex_reserve_sugggestion`.
     #[cfg(feature = "alloc")]
     fn append_hex_to_string(self, case: Case, string: &mut String) {
-        use fmt::Write;
-
         string.reserve(self.hex_reserve_suggestion());
-        match case {
-            Case::Lower => write!(string, "{:x}", self.as_hex()),
-            Case::Upper => write!(string, "{:X}", self.as_hex()),
+        write_hex(self, case, string)
+    }
+
+    /// Appends hex-encoded content as ASCII bytes to an existing `Vec<u8>`.
+    ///
+    /// This is useful when building a byte buffer of mixed binary and hex content. Like
+    /// `append_hex_to_string` it uses `hex_reserve_suggestion`.
+    #[cfg(feature = "alloc")]
+    fn append_hex_to_vec(self, case: Case, buf: &mut Vec<u8>) {
+        /// Writes ASCII hex characters directly into a byte vector.
+        struct VecWriter<'a>(&'a mut Vec<u8>);
+
+        impl fmt::Write for VecWriter<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.extend_from_slice(s.as_bytes());
+                Ok(())
+            }
         }
-        .unwrap_or_else(|_| {
-            let name = core::any::type_name::<Self::Display>();
-            // We don't expect `std` to ever be buggy, so the bug is most likely in the `Display`
-            // impl of `Self::Display`.
-            panic!("The implementation of Display for {} returned an error when it shouldn't", name)
-        })
+
+        buf.reserve(self.hex_reserve_suggestion());
+        write_hex(self, case, &mut VecWriter(buf))
+    }
+
+    /// Returns the exact number of hex chars produced by displaying `Self`.
+    ///
+    /// Unlike [`hex_reserve_suggestion`](Self::hex_reserve_suggestion) this is never a guess, it
+    /// does not include any `0x` prefix or padding. The default implementation counts the chars
+    /// by formatting without allocating, implementors that know the length upfront should
+    /// override this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0xde, 0xad, 0xbe, 0xef];
+    /// assert_eq!(v.hex_len(), 8);
+    /// assert_eq!([0u8; 3].hex_len(), 6);
+    /// ```
+    fn hex_len(self) -> usize {
+        /// Counts the bytes written to it.
+        struct Counter(usize);
+
+        impl fmt::Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut counter = Counter(0);
+        fmt::write(&mut counter, format_args!("{:x}", self.as_hex()))
+            .expect("counting never fails");
+        counter.0
     }
 
     /// Hints how much bytes to reserve when creating a `String`.
@@ -108,52 +193,128 @@ pub trait DisplayHex: Copy + sealed::IsRef {
     fn hex_reserve_suggestion(self) -> usize { 0 }
 }
 
+/// Writes `value` as hex to `writer`, panicking if the `Display` implementation errors.
+#[cfg(feature = "alloc")]
+fn write_hex<T: DisplayHex, W: fmt::Write>(value: T, case: Case, writer: &mut W) {
+    match case {
+        Case::Lower => write!(writer, "{:x}", value.as_hex()),
+        Case::Upper => write!(writer, "{:X}", value.as_hex()),
+    }
+    .unwrap_or_else(|_| {
+        let name = core::any::type_name::<T::Display>();
+        // We don't expect `std` to ever be buggy, so the bug is most likely in the `Display`
+        // impl of `T::Display`.
+        panic!("The implementation of Display for {} returned an error when it shouldn't", name)
+    })
+}
+
 fn internal_display(bytes: &[u8], f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+    internal_display_with(bytes, f, case, f.precision(), None)
+}
+
+/// Displays `bytes` using `precision` (counted in hex chars) instead of the formatter's own and
+/// pads with `pattern` instead of the formatter's fill char if it is set.
+fn internal_display_with(
+    bytes: &[u8],
+    f: &mut fmt::Formatter,
+    case: Case,
+    precision: Option<usize>,
+    pattern: Option<&str>,
+) -> fmt::Result {
+    internal_display_parts([bytes, &[]], f, case, precision, pattern, Order::Forward)
+}
+
+/// Order in which bytes are displayed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Order {
+    /// First byte first.
+    Forward,
+    /// Last byte first.
+    Reverse,
+}
+
+/// Displays the concatenation of the two `parts` like [`internal_display_with`].
+///
+/// This supports containers that are not contiguous in memory such as `VecDeque`. With
+/// [`Order::Reverse`] the bytes of the concatenation are displayed last to first, precision
+/// then keeps the last bytes.
+fn internal_display_parts(
+    parts: [&[u8]; 2],
+    f: &mut fmt::Formatter,
+    case: Case,
+    precision: Option<usize>,
+    pattern: Option<&str>,
+    order: Order,
+) -> fmt::Result {
     use fmt::Write;
-    // There are at least two optimizations left:
-    //
-    // * Reusing the buffer (encoder) which may decrease the number of virtual calls
-    // * Not recursing, avoiding another 1024B allocation and zeroing
-    //
-    // This would complicate the code so I was too lazy to do them but feel free to send a PR!
 
+    // In reverse order the second part is displayed first.
+    let [first, second] = match order {
+        Order::Forward => parts,
+        Order::Reverse => [parts[1], parts[0]],
+    };
+    let len = first.len() + second.len();
     let mut encoder = BufEncoder::<1024>::new(case);
-    let pad_right = write_pad_left(f, bytes.len(), &mut encoder)?;
+    let pad_right = write_pad_left(f, len, precision, pattern, &mut encoder)?;
 
     if f.alternate() {
         f.write_str("0x")?;
     }
-    match f.precision() {
-        Some(max) if bytes.len() > max / 2 => {
-            write!(f, "{}", bytes[..(max / 2)].as_hex())?;
-            if max % 2 == 1 {
-                f.write_char(case.table().byte_to_chars(bytes[max / 2])[0])?;
-            }
-        }
-        Some(_) | None => {
-            let mut chunks = bytes.chunks_exact(512);
-            for chunk in &mut chunks {
-                encoder.put_bytes(chunk);
-                f.write_str(encoder.as_str())?;
+    let (limit, half) = match precision {
+        Some(max) if len > max / 2 => (max / 2, max % 2 == 1),
+        Some(_) | None => (len, false),
+    };
+    let split = core::cmp::min(limit, first.len());
+    write_part(first, split, order, &mut encoder, f)?;
+    write_part(second, limit - split, order, &mut encoder, f)?;
+    if half {
+        let (part, index) =
+            if limit < first.len() { (first, limit) } else { (second, limit - first.len()) };
+        let byte = match order {
+            Order::Forward => part[index],
+            Order::Reverse => part[part.len() - 1 - index],
+        };
+        f.write_char(case.table().byte_to_chars(byte)[0])?;
+    }
+
+    write_pad_right(f, pad_right, pattern, &mut encoder)
+}
+
+/// Writes the first `n` bytes of `part` in the given `order`.
+///
+/// In reverse order these are the last `n` bytes of `part`, last byte first.
+fn write_part(
+    part: &[u8],
+    n: usize,
+    order: Order,
+    encoder: &mut BufEncoder<1024>,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    match order {
+        Order::Forward => encoder.write_slice_to(&part[..n], f),
+        Order::Reverse => {
+            for chunk in part[(part.len() - n)..].rchunks(512) {
                 encoder.clear();
+                encoder.put_bytes(chunk.iter().rev());
+                f.write_str(encoder.as_str())?;
             }
-            encoder.put_bytes(chunks.remainder());
-            f.write_str(encoder.as_str())?;
+            encoder.clear();
+            Ok(())
         }
     }
-
-    write_pad_right(f, pad_right, &mut encoder)
 }
 
 fn write_pad_left(
     f: &mut fmt::Formatter,
     bytes_len: usize,
+    precision: Option<usize>,
+    pattern: Option<&str>,
     encoder: &mut BufEncoder<1024>,
 ) -> Result<usize, fmt::Error> {
     let pad_right = if let Some(width) = f.width() {
         // Add space for 2 characters if the '#' flag is set
         let full_string_len = if f.alternate() { bytes_len * 2 + 2 } else { bytes_len * 2 };
-        let string_len = match f.precision() {
+        let string_len = match precision {
             Some(max) => core::cmp::min(max, full_string_len),
             None => full_string_len,
         };
@@ -166,6 +327,10 @@ fn write_pad_left(
             };
             // Avoid division by zero and optimize for common case.
             if left > 0 {
+                if let Some(pattern) = pattern.filter(|p| !p.is_empty()) {
+                    write_pattern(f, pattern, left)?;
+                    return Ok(right);
+                }
                 let c = f.fill();
                 let chunk_len = encoder.put_filler(c, left);
                 let padding = encoder.as_str();
@@ -188,10 +353,14 @@ fn write_pad_left(
 fn write_pad_right(
     f: &mut fmt::Formatter,
     pad_right: usize,
+    pattern: Option<&str>,
     encoder: &mut BufEncoder<1024>,
 ) -> fmt::Result {
     // Avoid division by zero and optimize for common case.
     if pad_right > 0 {
+        if let Some(pattern) = pattern.filter(|p| !p.is_empty()) {
+            return write_pattern(f, pattern, pad_right);
+        }
         encoder.clear();
         let c = f.fill();
         let chunk_len = encoder.put_filler(c, pad_right);
@@ -204,6 +373,18 @@ fn write_pad_right(
     Ok(())
 }
 
+/// Writes `count` chars of `pattern` repeated, the last repetition may be cut short.
+///
+/// `pattern` must not be empty.
+fn write_pattern(f: &mut fmt::Formatter, pattern: &str, count: usize) -> fmt::Result {
+    let pattern_len = pattern.chars().count();
+    for _ in 0..(count / pattern_len) {
+        f.write_str(pattern)?;
+    }
+    let end = pattern.char_indices().nth(count % pattern_len).map_or(pattern.len(), |(i, _)| i);
+    f.write_str(&pattern[..end])
+}
+
 mod sealed {
     /// Trait marking a shared reference.
     pub trait IsRef: Copy {}
@@ -218,12 +399,15 @@ impl<'a> DisplayHex for &'a [u8] {
     fn as_hex(self) -> Self::Display { DisplayByteSlice { bytes: self } }
 
     #[inline]
-    fn hex_reserve_suggestion(self) -> usize {
+    fn hex_len(self) -> usize {
         // Since the string wouldn't fit into address space if this overflows (actually even for
         // smaller amounts) it's better to panic right away. It should also give the optimizer
         // better opportunities.
         self.len().checked_mul(2).expect("the string wouldn't fit into address space")
     }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self.hex_len() }
 }
 
 #[cfg(feature = "alloc")]
@@ -234,23 +418,186 @@ impl<'a> DisplayHex for &'a alloc::vec::Vec<u8> {
     fn as_hex(self) -> Self::Display { DisplayByteSlice { bytes: self } }
 
     #[inline]
-    fn hex_reserve_suggestion(self) -> usize {
+    fn hex_len(self) -> usize {
+        // Since the string wouldn't fit into address space if this overflows (actually even for
+        // smaller amounts) it's better to panic right away. It should also give the optimizer
+        // better opportunities.
+        self.len().checked_mul(2).expect("the string wouldn't fit into address space")
+    }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self.hex_len() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DisplayHex for &'a alloc::boxed::Box<[u8]> {
+    type Display = DisplayByteSlice<'a>;
+
+    #[inline]
+    fn as_hex(self) -> Self::Display { self[..].as_hex() }
+
+    #[inline]
+    fn hex_len(self) -> usize { self[..].hex_len() }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self[..].hex_reserve_suggestion() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DisplayHex for &'a alloc::borrow::Cow<'_, [u8]> {
+    type Display = DisplayByteSlice<'a>;
+
+    #[inline]
+    fn as_hex(self) -> Self::Display { self[..].as_hex() }
+
+    #[inline]
+    fn hex_len(self) -> usize { self[..].hex_len() }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self[..].hex_reserve_suggestion() }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DisplayHex for &'a alloc::collections::VecDeque<u8> {
+    type Display = DisplayVecDeque<'a>;
+
+    #[inline]
+    fn as_hex(self) -> Self::Display {
+        let (first, second) = self.as_slices();
+        DisplayVecDeque { first, second }
+    }
+
+    #[inline]
+    fn hex_len(self) -> usize {
         // Since the string wouldn't fit into address space if this overflows (actually even for
         // smaller amounts) it's better to panic right away. It should also give the optimizer
         // better opportunities.
         self.len().checked_mul(2).expect("the string wouldn't fit into address space")
     }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self.hex_len() }
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> DisplayHex for &'a bytes::Bytes {
+    type Display = DisplayByteSlice<'a>;
+
+    #[inline]
+    fn as_hex(self) -> Self::Display { self[..].as_hex() }
+
+    #[inline]
+    fn hex_len(self) -> usize { self[..].hex_len() }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self[..].hex_reserve_suggestion() }
+}
+
+#[cfg(feature = "bytes")]
+impl<'a> DisplayHex for &'a bytes::BytesMut {
+    type Display = DisplayByteSlice<'a>;
+
+    #[inline]
+    fn as_hex(self) -> Self::Display { self[..].as_hex() }
+
+    #[inline]
+    fn hex_len(self) -> usize { self[..].hex_len() }
+
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self[..].hex_reserve_suggestion() }
 }
 
 /// Displays byte slice as hex.
 ///
 /// Created by [`<&[u8] as DisplayHex>::as_hex`](DisplayHex::as_hex).
+///
+/// Comparison and hashing use the underlying bytes. For inputs of the same length this orders the
+/// same as comparing the hex strings, shorter inputs which are a prefix of longer ones sort first.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DisplayByteSlice<'a> {
     // pub because we want to keep lengths in sync
     pub(crate) bytes: &'a [u8],
 }
 
-impl DisplayByteSlice<'_> {
+impl<'a> DisplayByteSlice<'a> {
+    /// Displays only the first `n` bytes (`2 * n` hex chars) of the slice.
+    ///
+    /// Unlike the formatter precision, which counts hex chars, this counts bytes. The formatter
+    /// precision is ignored by the returned value, width, fill, alignment and `#` still apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0xde, 0xad, 0xbe, 0xef];
+    /// assert_eq!(format!("{}", v.as_hex().truncate_bytes(2)), "dead");
+    /// assert_eq!(format!("{:.1}", v.as_hex().truncate_bytes(2)), "dead");
+    /// assert_eq!(format!("{}", v.as_hex().truncate_bytes(8)), "deadbeef");
+    /// ```
+    #[inline]
+    pub fn truncate_bytes(&self, n: usize) -> DisplayTruncated<'a> {
+        DisplayTruncated::new(self.bytes, n)
+    }
+
+    /// Displays only the last `n` bytes (`2 * n` hex chars) of the slice.
+    ///
+    /// This is the counterpart of [`truncate_bytes`](Self::truncate_bytes) keeping the least
+    /// significant bytes of a big-endian value. Padding is computed from the kept bytes only, the
+    /// formatter precision is ignored while width, fill, alignment and `#` still apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0x12, 0x34, 0x56, 0x78];
+    /// assert_eq!(format!("{}", v.as_hex().keep_last(2)), "5678");
+    /// assert_eq!(format!("{:>6}", v.as_hex().keep_last(2)), "  5678");
+    /// assert_eq!(format!("{}", v.as_hex().keep_last(8)), "12345678");
+    /// ```
+    #[inline]
+    pub fn keep_last(&self, n: usize) -> DisplayTruncated<'a> {
+        DisplayTruncated::new_last(self.bytes, n)
+    }
+
+    /// Pads with the repeated `pattern` instead of the formatter's fill char.
+    ///
+    /// The width is counted in chars, if the padding is not a multiple of the length of
+    /// `pattern` the last repetition is cut short. An empty `pattern` uses the formatter's fill
+    /// char.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0xde, 0xad];
+    /// assert_eq!(format!("{:>9}", v.as_hex().pad_with("·-")), "·-·-·dead");
+    /// assert_eq!(format!("{:^9}", v.as_hex().pad_with("·-")), "·-dead·-·");
+    /// ```
+    #[inline]
+    pub fn pad_with<'b>(&self, pattern: &'b str) -> DisplayPadded<'a, 'b> {
+        DisplayPadded { bytes: self.bytes, pattern }
+    }
+
+    /// Displays the bytes in reverse order.
+    ///
+    /// This is useful for little-endian values which are conventionally displayed most significant
+    /// byte first. Width, fill, alignment, precision and `#` apply to the reversed hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::DisplayHex;
+    ///
+    /// let v = vec![0xef, 0xbe, 0xad, 0xde];
+    /// assert_eq!(format!("{}", v.as_hex().rev()), "deadbeef");
+    /// assert_eq!(format!("{:#.5X}", v.as_hex().rev()), "0xDEADB");
+    /// ```
+    #[inline]
+    pub fn rev(&self) -> DisplayReversed<'a> { DisplayReversed { bytes: self.bytes } }
+
     fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
         internal_display(self.bytes, f, case)
     }
@@ -272,25 +619,324 @@ impl fmt::UpperHex for DisplayByteSlice<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
 }
 
+/// Displays at most a fixed number of bytes as hex.
+///
+/// Created by [`DisplayByteSlice::truncate_bytes`], [`DisplayByteSlice::keep_last`] and their
+/// [`DisplayArray`] counterparts.
+pub struct DisplayTruncated<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DisplayTruncated<'a> {
+    fn new(bytes: &'a [u8], n: usize) -> Self {
+        DisplayTruncated { bytes: &bytes[..core::cmp::min(n, bytes.len())] }
+    }
+
+    fn new_last(bytes: &'a [u8], n: usize) -> Self {
+        DisplayTruncated { bytes: &bytes[bytes.len().saturating_sub(n)..] }
+    }
+
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        internal_display_with(self.bytes, f, case, None, None)
+    }
+}
+
+impl fmt::Display for DisplayTruncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::Debug for DisplayTruncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::LowerHex for DisplayTruncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+impl fmt::UpperHex for DisplayTruncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
+/// Displays bytes as hex in reverse order.
+///
+/// Created by [`DisplayByteSlice::rev`] or [`DisplayArray::rev`].
+pub struct DisplayReversed<'a> {
+    bytes: &'a [u8],
+}
+
+impl DisplayReversed<'_> {
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        internal_display_parts([self.bytes, &[]], f, case, f.precision(), None, Order::Reverse)
+    }
+}
+
+impl fmt::Display for DisplayReversed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::Debug for DisplayReversed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::LowerHex for DisplayReversed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+impl fmt::UpperHex for DisplayReversed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
+/// Lazily displays the bytes yielded by `iter` as hex.
+///
+/// This avoids collecting an iterator adapter into a `Vec` only to display it. The iterator is
+/// cloned each time the returned value is formatted. Width, fill, alignment, precision and `#`
+/// are supported, if a width is set the iterator is additionally cloned once to count the bytes.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::display::display_iter;
+/// use hex_conservative::Case;
+///
+/// let bytes = [0xde, 0x00, 0xad, 0x00];
+/// let nonzero = bytes.iter().filter(|b| **b != 0);
+/// assert_eq!(format!("{}", display_iter(nonzero.clone(), Case::Lower)), "dead");
+/// assert_eq!(format!("{:>#8}", display_iter(nonzero, Case::Upper)), "  0xDEAD");
+/// ```
+#[inline]
+pub fn display_iter<I>(iter: I, case: Case) -> DisplayIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<u8>,
+{
+    DisplayIter { iter, case }
+}
+
+/// Displays the bytes yielded by an iterator as hex.
+///
+/// Created by [`display_iter`].
+pub struct DisplayIter<I> {
+    iter: I,
+    case: Case,
+}
+
+impl<I> fmt::Display for DisplayIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write;
+
+        // The length only matters for padding, don't iterate twice if there is none.
+        let len = if f.width().is_some() { self.iter.clone().into_iter().count() } else { 0 };
+        let mut encoder = BufEncoder::<1024>::new(self.case);
+        let pad_right = write_pad_left(f, len, f.precision(), None, &mut encoder)?;
+
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        let mut remaining = f.precision().unwrap_or(usize::MAX);
+        let mut half = None;
+        for byte in self.iter.clone() {
+            let byte = *byte.borrow();
+            if remaining < 2 {
+                half = Some(byte).filter(|_| remaining == 1);
+                break;
+            }
+            if encoder.is_full() {
+                f.write_str(encoder.as_str())?;
+                encoder.clear();
+            }
+            encoder.put_byte(byte);
+            remaining -= 2;
+        }
+        f.write_str(encoder.as_str())?;
+        if let Some(byte) = half {
+            f.write_char(self.case.table().byte_to_chars(byte)[0])?;
+        }
+
+        write_pad_right(f, pad_right, None, &mut encoder)
+    }
+}
+
+impl<I> fmt::Debug for DisplayIter<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Borrow<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+}
+
+/// Displays a `VecDeque` as hex.
+///
+/// Created by [`<&VecDeque<u8> as DisplayHex>::as_hex`](DisplayHex::as_hex). The contents are
+/// displayed in logical order even if they wrap around the end of the ring buffer.
+#[cfg(feature = "alloc")]
+pub struct DisplayVecDeque<'a> {
+    first: &'a [u8],
+    second: &'a [u8],
+}
+
+#[cfg(feature = "alloc")]
+impl DisplayVecDeque<'_> {
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        let parts = [self.first, self.second];
+        internal_display_parts(parts, f, case, f.precision(), None, Order::Forward)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for DisplayVecDeque<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for DisplayVecDeque<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::LowerHex for DisplayVecDeque<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::UpperHex for DisplayVecDeque<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
+/// Displays hex in upper-case using `Display`.
+///
+/// Created by [`DisplayHex::as_hex_upper`].
+pub struct DisplayUpper<T>(T);
+
+impl<T: fmt::UpperHex> fmt::Display for DisplayUpper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::UpperHex::fmt(&self.0, f) }
+}
+
+impl<T: fmt::UpperHex> fmt::Debug for DisplayUpper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::UpperHex::fmt(&self.0, f) }
+}
+
+impl<T: fmt::LowerHex> fmt::LowerHex for DisplayUpper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(&self.0, f) }
+}
+
+impl<T: fmt::UpperHex> fmt::UpperHex for DisplayUpper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::UpperHex::fmt(&self.0, f) }
+}
+
+/// Displays bytes as hex padded with a repeated pattern.
+///
+/// Created by [`DisplayByteSlice::pad_with`] or [`DisplayArray::pad_with`].
+pub struct DisplayPadded<'a, 'b> {
+    bytes: &'a [u8],
+    pattern: &'b str,
+}
+
+impl DisplayPadded<'_, '_> {
+    fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
+        internal_display_with(self.bytes, f, case, f.precision(), Some(self.pattern))
+    }
+}
+
+impl fmt::Display for DisplayPadded<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::Debug for DisplayPadded<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::LowerHex::fmt(self, f) }
+}
+
+impl fmt::LowerHex for DisplayPadded<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Lower) }
+}
+
+impl fmt::UpperHex for DisplayPadded<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
+}
+
 /// Displays byte array as hex.
 ///
-/// Created by [`<&[u8; CAP / 2] as DisplayHex>::as_hex`](DisplayHex::as_hex).
-pub struct DisplayArray<'a, const CAP: usize> {
-    array: &'a [u8],
+/// Created by [`<&[u8; LEN] as DisplayHex>::as_hex`](DisplayHex::as_hex).
+///
+/// Comparison and hashing use the underlying bytes, which orders the same as comparing the hex
+/// strings.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DisplayArray<'a, const LEN: usize> {
+    array: &'a [u8; LEN],
 }
 
-impl<'a, const CAP: usize> DisplayArray<'a, CAP> {
+impl<'a, const LEN: usize> DisplayArray<'a, LEN> {
+    /// The number of hex characters produced when displaying the array (without any `0x` prefix
+    /// or padding).
+    pub const ENCODED_LEN: usize = LEN * 2;
+
     /// Creates the wrapper.
+    #[inline]
+    fn new(array: &'a [u8; LEN]) -> Self { DisplayArray { array } }
+
+    /// Creates the wrapper from a slice whose length is only known at runtime.
+    ///
+    /// # Errors
+    ///
+    /// If the length of `bytes` is not `LEN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::display::DisplayArray;
+    ///
+    /// let v = vec![0xde, 0xad, 0xbe, 0xef];
+    /// let display = DisplayArray::<4>::try_new(&v).expect("correct length");
+    /// assert_eq!(format!("{}", display), "deadbeef");
+    /// assert!(DisplayArray::<32>::try_new(&v).is_err());
+    /// ```
+    #[inline]
+    pub fn try_new(bytes: &'a [u8]) -> Result<Self, SliceLengthError> {
+        match bytes.try_into() {
+            Ok(array) => Ok(Self::new(array)),
+            Err(_) => Err(SliceLengthError { len: bytes.len(), expected: LEN }),
+        }
+    }
+
+    /// Returns the number of hex characters produced when displaying the array.
     ///
-    /// # Panics
+    /// This is the same as [`Self::ENCODED_LEN`] and does not include any `0x` prefix or padding.
+    #[inline]
+    pub fn encoded_len(&self) -> usize { Self::ENCODED_LEN }
+
+    /// Displays only the first `n` bytes (`2 * n` hex chars) of the array.
     ///
-    /// When the length of array is greater than capacity / 2.
+    /// See [`DisplayByteSlice::truncate_bytes`] for details.
     #[inline]
-    fn new(array: &'a [u8]) -> Self {
-        assert!(array.len() <= CAP / 2);
-        DisplayArray { array }
+    pub fn truncate_bytes(&self, n: usize) -> DisplayTruncated<'a> {
+        DisplayTruncated::new(self.array, n)
     }
 
+    /// Displays only the last `n` bytes (`2 * n` hex chars) of the array.
+    ///
+    /// See [`DisplayByteSlice::keep_last`] for details.
+    #[inline]
+    pub fn keep_last(&self, n: usize) -> DisplayTruncated<'a> {
+        DisplayTruncated::new_last(self.array, n)
+    }
+
+    /// Pads with the repeated `pattern` instead of the formatter's fill char.
+    ///
+    /// See [`DisplayByteSlice::pad_with`] for details.
+    #[inline]
+    pub fn pad_with<'b>(&self, pattern: &'b str) -> DisplayPadded<'a, 'b> {
+        DisplayPadded { bytes: self.array, pattern }
+    }
+
+    /// Displays the bytes in reverse order.
+    ///
+    /// See [`DisplayByteSlice::rev`] for details.
+    #[inline]
+    pub fn rev(&self) -> DisplayReversed<'a> { DisplayReversed { bytes: self.array } }
+
     fn display(&self, f: &mut fmt::Formatter, case: Case) -> fmt::Result {
         internal_display(self.array, f, case)
     }
@@ -312,24 +958,23 @@ impl<const LEN: usize> fmt::UpperHex for DisplayArray<'_, LEN> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.display(f, Case::Upper) }
 }
 
-macro_rules! impl_array_as_hex {
-    ($($len:expr),*) => {
-        $(
-            impl<'a> DisplayHex for &'a [u8; $len] {
-                type Display = DisplayArray<'a, {$len * 2}>;
+impl<'a, const LEN: usize> DisplayHex for &'a [u8; LEN] {
+    type Display = DisplayArray<'a, LEN>;
 
-                fn as_hex(self) -> Self::Display {
-                    DisplayArray::new(self)
-                }
-            }
-        )*
+    #[inline]
+    fn as_hex(self) -> Self::Display { DisplayArray::new(self) }
+
+    #[inline]
+    fn hex_len(self) -> usize {
+        // Since the string wouldn't fit into address space if this overflows (actually even for
+        // smaller amounts) it's better to panic right away. It should also give the optimizer
+        // better opportunities.
+        LEN.checked_mul(2).expect("the string wouldn't fit into address space")
     }
-}
 
-impl_array_as_hex!(
-    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 20, 32, 33, 64, 65, 128, 256, 512, 1024,
-    2048, 4096
-);
+    #[inline]
+    fn hex_reserve_suggestion(self) -> usize { self.hex_len() }
+}
 
 /// Format known-length array as hex.
 ///
@@ -348,11 +993,31 @@ impl_array_as_hex!(
 /// * `$len` known length of `$bytes`, must be a const expression.
 /// * `$bytes` - bytes to be encoded, most likely a reference to an array.
 /// * `$case` - value of type [`Case`] determining whether to format as lower or upper case.
+/// * `$reverse` - optional, if true the bytes are displayed backwards. This requires the iterator
+///   of `$bytes` to be double-ended, as is the case for arrays and slices.
 ///
 /// ## Panics
 ///
 /// This macro panics if `$len` is not equal to `$bytes.len()`. It also fails to compile if `$len`
 /// is more than half of `usize::MAX`.
+///
+/// ## Examples
+///
+/// ```
+/// use core::fmt;
+///
+/// use hex_conservative::{fmt_hex_exact, Case};
+///
+/// struct LittleEndian([u8; 4]);
+///
+/// impl fmt::LowerHex for LittleEndian {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         fmt_hex_exact!(f, 4, &self.0, Case::Lower, true)
+///     }
+/// }
+///
+/// assert_eq!(format!("{:x}", LittleEndian([0x78, 0x56, 0x34, 0x12])), "12345678");
+/// ```
 #[macro_export]
 macro_rules! fmt_hex_exact {
     ($formatter:expr, $len:expr, $bytes:expr, $case:expr) => {{
@@ -362,6 +1027,15 @@ macro_rules! fmt_hex_exact {
         assert_eq!($bytes.len(), $len);
         $crate::display::fmt_hex_exact_fn::<_, { $len * 2 }>($formatter, $bytes, $case)
     }};
+    ($formatter:expr, $len:expr, $bytes:expr, $case:expr, $reverse:expr) => {{
+        let bytes = $bytes;
+        if $reverse {
+            let bytes = $crate::_export::_core::iter::IntoIterator::into_iter(bytes).rev();
+            $crate::fmt_hex_exact!($formatter, $len, bytes, $case)
+        } else {
+            $crate::fmt_hex_exact!($formatter, $len, bytes, $case)
+        }
+    }};
 }
 pub use fmt_hex_exact;
 
@@ -376,6 +1050,9 @@ pub use fmt_hex_exact;
 ///
 /// - `$ty` must implement `IntoIterator<Item=Borrow<u8>>`.
 ///
+/// Use [`impl_parse_traits`](crate::impl_parse_traits) with the same input to also implement
+/// `FromStr`.
+///
 /// ## Parameters
 ///
 /// * `$ty` - the type to implement traits on.
@@ -483,30 +1160,16 @@ macro_rules! impl_fmt_traits {
         impl<$($gen: $gent),*> $crate::_export::_core::fmt::LowerHex for $ty<$($gen),*> {
             #[inline]
             fn fmt(&self, f: &mut $crate::_export::_core::fmt::Formatter) -> $crate::_export::_core::fmt::Result {
-                let case = $crate::Case::Lower;
-
-                if $reverse {
-                    let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self).iter().rev();
-                    $crate::fmt_hex_exact!(f, $len, bytes, case)
-                } else {
-                    let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self).iter();
-                    $crate::fmt_hex_exact!(f, $len, bytes, case)
-                }
+                let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self);
+                $crate::fmt_hex_exact!(f, $len, bytes, $crate::Case::Lower, $reverse)
             }
         }
 
         impl<$($gen: $gent),*> $crate::_export::_core::fmt::UpperHex for $ty<$($gen),*> {
             #[inline]
             fn fmt(&self, f: &mut $crate::_export::_core::fmt::Formatter) -> $crate::_export::_core::fmt::Result {
-                let case = $crate::Case::Upper;
-
-                if $reverse {
-                    let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self).iter().rev();
-                    $crate::fmt_hex_exact!(f, $len, bytes, case)
-                } else {
-                    let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self).iter();
-                    $crate::fmt_hex_exact!(f, $len, bytes, case)
-                }
+                let bytes = $crate::_export::_core::borrow::Borrow::<[u8]>::borrow(self);
+                $crate::fmt_hex_exact!(f, $len, bytes, $crate::Case::Upper, $reverse)
             }
         }
 
@@ -546,7 +1209,7 @@ where
     I::Item: Borrow<u8>,
 {
     let mut padding_encoder = BufEncoder::<1024>::new(case);
-    let pad_right = write_pad_left(f, N / 2, &mut padding_encoder)?;
+    let pad_right = write_pad_left(f, N / 2, f.precision(), None, &mut padding_encoder)?;
 
     if f.alternate() {
         f.write_str("0x")?;
@@ -565,20 +1228,19 @@ where
     };
     f.write_str(encoded)?;
 
-    write_pad_right(f, pad_right, &mut padding_encoder)
+    write_pad_right(f, pad_right, None, &mut padding_encoder)
 }
 
-/// Given a `T:` [`fmt::Write`], `HexWriter` implements [`std::io::Write`]
-/// and writes the source bytes to its inner `T` as hex characters.
-#[cfg(any(test, feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(any(test, feature = "std"))))]
+/// Given a `T:` [`fmt::Write`], `HexWriter` writes the source bytes to its inner `T` as hex
+/// characters.
+///
+/// Bytes can be written using [`HexWriter::write_bytes`] in `no_std` environments, with the `std`
+/// feature enabled `HexWriter` also implements [`std::io::Write`].
 pub struct HexWriter<T> {
     writer: T,
     table: &'static Table,
 }
 
-#[cfg(any(test, feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(any(test, feature = "std"))))]
 impl<T> HexWriter<T> {
     /// Creates a `HexWriter` that writes the source bytes to `dest` as hex characters
     /// in the given `case`.
@@ -587,8 +1249,31 @@ impl<T> HexWriter<T> {
     pub fn into_inner(self) -> T { self.writer }
 }
 
-#[cfg(any(test, feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(any(test, feature = "std"))))]
+impl<T> HexWriter<T>
+where
+    T: fmt::Write,
+{
+    /// Writes all the bytes in `buf` to the inner writer as hex characters.
+    ///
+    /// # Errors
+    ///
+    /// If the inner writer returns an error, in which case only some of the bytes may have been
+    /// written.
+    pub fn write_bytes(&mut self, buf: &[u8]) -> fmt::Result {
+        for byte in buf {
+            self.write_byte(*byte)?;
+        }
+        Ok(())
+    }
+
+    fn write_byte(&mut self, byte: u8) -> fmt::Result {
+        let mut hex_chars = [0u8; 2];
+        let hex_str = self.table.byte_to_str(&mut hex_chars, byte);
+        self.writer.write_str(hex_str)
+    }
+}
+
+#[cfg(feature = "std")]
 impl<T> std::io::Write for HexWriter<T>
 where
     T: core::fmt::Write,
@@ -600,9 +1285,7 @@ where
     fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
         let mut n = 0;
         for byte in buf {
-            let mut hex_chars = [0u8; 2];
-            let hex_str = self.table.byte_to_str(&mut hex_chars, *byte);
-            if self.writer.write_str(hex_str).is_err() {
+            if self.write_byte(*byte).is_err() {
                 break;
             }
             n += 1;
@@ -616,6 +1299,71 @@ where
     fn flush(&mut self) -> Result<(), std::io::Error> { Ok(()) }
 }
 
+/// Given a `W:` [`std::io::Write`], `HexEncodingWriter` implements [`std::io::Write`] and
+/// forwards the source bytes to its inner `W` encoded as hex characters.
+#[cfg(feature = "std")]
+pub struct HexEncodingWriter<W> {
+    inner: W,
+    case: Case,
+    /// Second character of a byte whose first character was accepted by `inner`.
+    pending: Option<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<W> HexEncodingWriter<W> {
+    /// Creates a `HexEncodingWriter` that writes the source bytes to `inner` as hex characters
+    /// in the given `case`.
+    pub fn new(inner: W, case: Case) -> Self { Self { inner, case, pending: None } }
+    /// Consumes this `HexEncodingWriter` returning the inner `W`.
+    ///
+    /// Call [`flush`](std::io::Write::flush) first, a character still pending from a partial
+    /// write to `inner` is lost otherwise.
+    pub fn into_inner(self) -> W { self.inner }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> HexEncodingWriter<W> {
+    /// Writes the character left over from a previous partial write, if any.
+    fn write_pending(&mut self) -> Result<(), std::io::Error> {
+        if let Some(c) = self.pending {
+            self.inner.write_all(&[c])?;
+            self.pending = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> std::io::Write for HexEncodingWriter<W>
+where
+    W: std::io::Write,
+{
+    /// Encodes as many bytes from `buf` as the inner writer accepts.
+    ///
+    /// If the inner writer only accepts the first character of a byte's encoding, the byte is
+    /// counted as written and the second character is kept until the next call to `write` or
+    /// `flush`. An error is only returned if nothing from `buf` was written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
+        self.write_pending()?;
+
+        let mut encoder = BufEncoder::<1024>::new(self.case);
+        let to_write = encoder.space_remaining().min(buf.len());
+        encoder.put_bytes(&buf[..to_write]);
+        let hex = encoder.as_str().as_bytes();
+
+        let written = self.inner.write(hex)?;
+        if written % 2 == 1 {
+            self.pending = Some(hex[written]);
+        }
+        Ok((written + 1) / 2)
+    }
+
+    fn flush(&mut self) -> Result<(), std::io::Error> {
+        self.write_pending()?;
+        self.inner.flush()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "alloc")]
@@ -656,6 +1404,108 @@ mod tests {
         #[test]
         fn just_above_double_boundary() { check_encoding(&[42; 1025]); }
 
+        #[test]
+        fn append_hex_to_vec() {
+            let mut buf = vec![0x00, 0xff];
+            [0xde, 0xad].append_hex_to_vec(Case::Lower, &mut buf);
+            [0xbe, 0xef].as_slice().append_hex_to_vec(Case::Upper, &mut buf);
+            assert_eq!(buf, b"\x00\xffdeadBEEF");
+        }
+
+        #[test]
+        fn display_array_encoded_len() {
+            let array = [0xab; 32];
+            assert_eq!(DisplayArray::<32>::ENCODED_LEN, 64);
+            assert_eq!(array.as_hex().encoded_len(), 64);
+            assert_eq!(array.as_hex().encoded_len(), array.to_lower_hex_string().len());
+        }
+
+        #[test]
+        fn hex_len() {
+            use crate::alloc::collections::VecDeque;
+
+            let v = vec![0xab; 5];
+            assert_eq!(v.hex_len(), 10);
+            assert_eq!(v.as_slice().hex_len(), 10);
+            assert_eq!([0u8; 0].hex_len(), 0);
+            let deque: VecDeque<u8> = v.iter().copied().collect();
+            assert_eq!(deque.hex_len(), 10);
+
+            // The default implementation counts the formatted chars.
+            #[derive(Clone, Copy)]
+            struct Counted<'a>(&'a [u8]);
+
+            impl<'a> DisplayHex for &'a Counted<'a> {
+                type Display = DisplayByteSlice<'a>;
+
+                fn as_hex(self) -> Self::Display { self.0.as_hex() }
+            }
+
+            let counted = Counted(&v);
+            assert_eq!((&counted).hex_len(), 10);
+            assert_eq!((&counted).hex_reserve_suggestion(), 0);
+        }
+
+        #[test]
+        fn to_hex_string_exact() {
+            let v: Vec<u8> = (0..=255).cycle().take(3000).collect();
+            let s = v.to_hex_string_exact(Case::Upper);
+            assert_eq!(s, v.to_upper_hex_string());
+            assert_eq!(s.capacity(), 6000);
+            assert_eq!([0u8; 0].to_hex_string_exact(Case::Lower).capacity(), 0);
+        }
+
+        #[test]
+        fn boxed_and_cow() {
+            use crate::alloc::borrow::Cow;
+
+            let boxed: Box<[u8]> = vec![0xde, 0xad].into_boxed_slice();
+            assert_eq!(boxed.to_lower_hex_string(), "dead");
+            assert_eq!(boxed.hex_len(), 4);
+
+            let borrowed: Cow<[u8]> = Cow::Borrowed(&[0xbe, 0xef]);
+            assert_eq!(format!("{:X}", borrowed.as_hex()), "BEEF");
+            let owned: Cow<[u8]> = Cow::Owned(vec![0xbe, 0xef]);
+            assert_eq!(owned.to_lower_hex_string(), "beef");
+        }
+
+        #[test]
+        fn mutable_references() {
+            // Method calls auto-reborrow, no `&*` needed.
+            let mut v = vec![0xde, 0xad];
+            let slice: &mut [u8] = &mut v;
+            slice[1] = 0xaf;
+            assert_eq!(slice.as_hex().to_string(), "deaf");
+            assert_eq!(slice.to_upper_hex_string(), "DEAF");
+
+            let mut a = [0xbe, 0xef];
+            let array: &mut [u8; 2] = &mut a;
+            array[0] = 0xb0;
+            assert_eq!(array.as_hex().to_string(), "b0ef");
+            assert_eq!(array.hex_len(), 4);
+        }
+
+        #[test]
+        fn display_array_try_new() {
+            let v = vec![0xab; 3];
+            assert_eq!(DisplayArray::<3>::try_new(&v).unwrap().to_string(), "ababab");
+            assert_eq!(DisplayArray::<0>::try_new(&[]).unwrap().to_string(), "");
+            let err = DisplayArray::<2>::try_new(&v).unwrap_err();
+            assert_eq!((err.length(), err.expected_length()), (3, 2));
+            assert!(DisplayArray::<4>::try_new(&v).is_err());
+        }
+
+        #[test]
+        fn display_array_any_length() {
+            let array = [0xab; 48];
+            assert_eq!(array.as_hex().to_string(), "ab".repeat(48));
+            assert_eq!(array.to_lower_hex_string(), "ab".repeat(48));
+            assert_eq!((&array).hex_reserve_suggestion(), 96);
+
+            let array: [u8; 0] = [];
+            assert_eq!(array.to_lower_hex_string(), "");
+        }
+
         #[test]
         fn fmt_exact_macro() {
             use crate::alloc::string::ToString;
@@ -674,6 +1524,22 @@ mod tests {
             assert_eq!(format!("{:.65}", dummy), "2a".repeat(32));
         }
 
+        #[test]
+        fn fmt_exact_macro_reverse() {
+            struct Dummy([u8; 4], bool);
+
+            impl fmt::Display for Dummy {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt_hex_exact!(f, 4, &self.0, Case::Upper, self.1)
+                }
+            }
+            let bytes = [0x12, 0x34, 0x56, 0x78];
+            assert_eq!(Dummy(bytes, true).to_string(), "78563412");
+            assert_eq!(Dummy(bytes, false).to_string(), "12345678");
+            assert_eq!(format!("{:#.5}", Dummy(bytes, true)), "0x78563");
+            assert_eq!(format!("{:>10}", Dummy(bytes, true)), "  78563412");
+        }
+
         macro_rules! define_dummy {
             ($len:literal) => {
                 struct Dummy([u8; $len]);
@@ -816,6 +1682,162 @@ mod tests {
             test_display_hex!("{0:#.16}", [0x12, 0x34, 0x56, 0x78], "0x12345678");
         }
 
+        #[test]
+        #[cfg(feature = "bytes")]
+        fn display_bytes() {
+            let b = bytes::Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(format!("{}", b.as_hex()), "deadbeef");
+            assert_eq!(b.to_upper_hex_string(), "DEADBEEF");
+
+            let b = bytes::BytesMut::from(&[0xde, 0xad][..]);
+            assert_eq!(b.to_lower_hex_string(), "dead");
+        }
+
+        #[test]
+        fn display_vec_deque() {
+            use crate::alloc::collections::VecDeque;
+
+            let mut d = VecDeque::new();
+            d.extend([0x56, 0x78, 0x9a]);
+            d.push_front(0x34);
+            d.push_front(0x12);
+            // Make sure the contents wrap around.
+            assert!(!d.as_slices().1.is_empty());
+
+            assert_eq!(format!("{}", d.as_hex()), "123456789a");
+            assert_eq!(format!("{:X}", d.as_hex()), "123456789A");
+            assert_eq!(d.to_lower_hex_string(), "123456789a");
+            assert_eq!(format!("{:#}", d.as_hex()), "0x123456789a");
+            assert_eq!(format!("{:>12}", d.as_hex()), "  123456789a");
+            for (precision, want) in [
+                "",
+                "1",
+                "12",
+                "123",
+                "1234",
+                "12345",
+                "123456",
+                "1234567",
+                "12345678",
+                "123456789",
+                "123456789a",
+                "123456789a",
+            ]
+            .iter()
+            .enumerate()
+            {
+                assert_eq!(&format!("{:.*}", precision, d.as_hex()), want);
+            }
+
+            assert_eq!(format!("{}", VecDeque::new().as_hex()), "");
+        }
+
+        #[test]
+        fn display_upper() {
+            let v = vec![0xde, 0xad, 0xbe, 0xef];
+            assert_eq!(format!("{}", v.as_hex_upper()), "DEADBEEF");
+            assert_eq!(format!("{:?}", v.as_hex_upper()), "DEADBEEF");
+            assert_eq!(format!("{:x}", v.as_hex_upper()), "deadbeef");
+            assert_eq!(format!("{:#.4}", v.as_hex_upper()), "0xDEAD");
+            assert_eq!(format!("{:>10}", v.as_hex_upper()), "  DEADBEEF");
+
+            let a = [0xab; 2];
+            assert_eq!(format!("{}", a.as_hex_upper()), "ABAB");
+        }
+
+        #[test]
+        fn pad_with_pattern() {
+            let v = vec![0x12, 0x34];
+            assert_eq!(format!("{}", v.as_hex().pad_with("ab")), "1234");
+            assert_eq!(format!("{:8}", v.as_hex().pad_with("ab")), "1234abab");
+            assert_eq!(format!("{:<7}", v.as_hex().pad_with("xyz")), "1234xyz");
+            assert_eq!(format!("{:>9}", v.as_hex().pad_with("xyz")), "xyzxy1234");
+            assert_eq!(format!("{:^10}", v.as_hex().pad_with("«»")), "«»«1234«»«");
+            assert_eq!(format!("{:>#8}", v.as_hex().pad_with("-")), "--0x1234");
+            assert_eq!(format!("{:>8.2}", v.as_hex().pad_with("-")), "------12");
+            assert_eq!(format!("{:X>8}", v.as_hex().pad_with("")), "XXXX1234");
+            assert_eq!(format!("{:2}", v.as_hex().pad_with("-")), "1234");
+
+            let a = [0x12, 0x34];
+            assert_eq!(format!("{:>6}", a.as_hex().pad_with("·")), "··1234");
+        }
+
+        #[test]
+        fn truncate_bytes() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", v.as_hex().truncate_bytes(2)), "1234");
+            assert_eq!(format!("{:X}", v.as_hex().truncate_bytes(1)), "12");
+            assert_eq!(format!("{}", v.as_hex().truncate_bytes(0)), "");
+            assert_eq!(format!("{}", v.as_hex().truncate_bytes(5)), "12345678");
+            // The formatter precision is ignored, padding still applies.
+            assert_eq!(format!("{:.1}", v.as_hex().truncate_bytes(2)), "1234");
+            assert_eq!(format!("{:#>8.1}", v.as_hex().truncate_bytes(2)), "####1234");
+            assert_eq!(format!("{:#}", v.as_hex().truncate_bytes(2)), "0x1234");
+
+            let a = [0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", a.as_hex().truncate_bytes(3)), "123456");
+        }
+
+        #[test]
+        fn display_iter() {
+            let v: Vec<u8> = (0..=255).cycle().take(1500).collect();
+            let display = super::display_iter(&v, Case::Upper);
+            assert_eq!(display.to_string(), v.to_upper_hex_string());
+            assert_eq!(format!("{:.7}", display), format!("{:.7X}", v.as_hex()));
+            assert_eq!(format!("{:#.2501}", display), format!("{:#.2501X}", v.as_hex()));
+
+            let odd = [0x12, 0x00, 0x34, 0x00, 0x56].iter().filter(|b| **b != 0);
+            let display = super::display_iter(odd, Case::Lower);
+            assert_eq!(format!("{}", display), "123456");
+            assert_eq!(format!("{:?}", display), "123456");
+            assert_eq!(format!("{:.3}", display), "123");
+            assert_eq!(format!("{:*^10}", display), "**123456**");
+            let collected = [0x12, 0x34, 0x56];
+            assert_eq!(format!("{:>#10.4}", display), format!("{:>#10.4}", collected.as_hex()));
+            let empty = super::display_iter(core::iter::empty::<u8>(), Case::Lower);
+            assert_eq!(format!("{}", empty), "");
+        }
+
+        #[test]
+        fn keep_last() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", v.as_hex().keep_last(2)), "5678");
+            assert_eq!(format!("{:X}", v.as_hex().keep_last(1)), "78");
+            assert_eq!(format!("{}", v.as_hex().keep_last(0)), "");
+            assert_eq!(format!("{}", v.as_hex().keep_last(5)), "12345678");
+            assert_eq!(format!("{:.1}", v.as_hex().keep_last(2)), "5678");
+            assert_eq!(format!("{:#<8}", v.as_hex().keep_last(2)), "5678####");
+            assert_eq!(format!("{:#}", v.as_hex().keep_last(2)), "0x5678");
+
+            let a = [0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", a.as_hex().keep_last(3)), "345678");
+        }
+
+        #[test]
+        fn rev() {
+            let v = vec![0x12, 0x34, 0x56, 0x78];
+            assert_eq!(format!("{}", v.as_hex().rev()), "78563412");
+            assert_eq!(format!("{:X}", v.as_hex().rev()), "78563412");
+            assert_eq!(format!("{:.3}", v.as_hex().rev()), "785");
+            assert_eq!(format!("{:#.4}", v.as_hex().rev()), "0x7856");
+            assert_eq!(format!("{:*^12}", v.as_hex().rev()), "**78563412**");
+            assert_eq!(format!("{:>6.3}", v.as_hex().rev()), "   785");
+            assert_eq!(format!("{}", [0u8; 0].as_hex().rev()), "");
+
+            // Same as displaying the reversed bytes, including across chunk boundaries.
+            let v: Vec<u8> = (0..=255).cycle().take(1500).collect();
+            let reversed: Vec<u8> = v.iter().rev().copied().collect();
+            assert_eq!(format!("{:X}", v.as_hex().rev()), reversed.to_upper_hex_string());
+            assert_eq!(format!("{:.7}", v.as_hex().rev()), format!("{:.7}", reversed.as_hex()));
+            assert_eq!(
+                format!("{:#.2501}", v.as_hex().rev()),
+                format!("{:#.2501}", reversed.as_hex())
+            );
+
+            let a = [0x12, 0x34, 0x56];
+            assert_eq!(format!("{}", a.as_hex().rev()), "563412");
+        }
+
         #[test]
         fn padding_extends() {
             define_dummy!(2);
@@ -927,6 +1949,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn display_wrappers_compare_by_bytes() {
+        use super::DisplayHex;
+
+        let a = [0x00u8, 0xff];
+        let b = [0x01u8, 0x00];
+        assert!(a.as_hex() < b.as_hex());
+        assert_eq!(a.as_hex(), [0x00u8, 0xff].as_hex());
+
+        let mut slices = [&b[..], &a[..], &a[..1]];
+        slices.sort_by_key(|s| s.as_hex());
+        assert_eq!(slices, [&a[..1], &a[..], &b[..]]);
+    }
+
+    #[test]
+    fn hex_writer_write_bytes() {
+        use arrayvec::ArrayString;
+
+        use super::{Case, HexWriter};
+
+        let mut writer = HexWriter::new(ArrayString::<8>::new(), Case::Lower);
+        writer.write_bytes(&[]).unwrap();
+        writer.write_bytes(&[0xde, 0xad]).unwrap();
+        writer.write_bytes(&[0xbe, 0xef]).unwrap();
+        assert_eq!(writer.into_inner().as_str(), "deadbeef");
+
+        let mut writer = HexWriter::new(ArrayString::<3>::new(), Case::Upper);
+        assert!(writer.write_bytes(&[0xab, 0xcd]).is_err());
+        assert_eq!(writer.into_inner().as_str(), "AB");
+    }
+
     #[cfg(feature = "std")]
     mod std {
 
@@ -944,7 +1997,8 @@ mod tests {
                     let dest_buf = ArrayString::<$cap>::new();
                     let mut dest = HexWriter::new(dest_buf, $case);
                     let got = dest.write($src);
-                    match $want {
+                    let want: Result<usize> = $want;
+                    match want {
                         Ok(n) => assert_eq!(got.unwrap(), n),
                         Err(e) => assert_eq!(got.unwrap_err().kind(), e.kind()),
                     }
@@ -966,5 +2020,73 @@ mod tests {
             writer.write_all(&vec[..]).unwrap();
             assert_eq!(writer.into_inner(), vec.to_lower_hex_string());
         }
+
+        #[test]
+        fn hex_encoding_writer() {
+            use std::io::Write;
+
+            use super::Case::{Lower, Upper};
+            use super::{DisplayHex, HexEncodingWriter};
+
+            let mut writer = HexEncodingWriter::new(Vec::new(), Lower);
+            assert_eq!(writer.write(&[]).unwrap(), 0);
+            assert_eq!(writer.write(&[0xab, 0xcd]).unwrap(), 2);
+            assert_eq!(writer.into_inner(), b"abcd");
+
+            let vec: Vec<_> = (0u8..=255).cycle().take(2000).collect();
+            let mut writer = HexEncodingWriter::new(Vec::new(), Upper);
+            writer.write_all(&vec[..]).unwrap();
+            assert_eq!(writer.into_inner(), vec.to_upper_hex_string().into_bytes());
+        }
+
+        #[test]
+        fn hex_encoding_writer_partial() {
+            use std::io::{Result, Write};
+
+            use super::Case::Lower;
+            use super::HexEncodingWriter;
+
+            // Accepts at most three bytes per call to `write`.
+            struct Partial(Vec<u8>);
+
+            impl Write for Partial {
+                fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                    let n = buf.len().min(3);
+                    self.0.extend_from_slice(&buf[..n]);
+                    Ok(n)
+                }
+                fn flush(&mut self) -> Result<()> { Ok(()) }
+            }
+
+            let mut writer = HexEncodingWriter::new(Partial(Vec::new()), Lower);
+            assert_eq!(writer.write(&[0xde, 0xad, 0xbe, 0xef]).unwrap(), 2);
+            assert_eq!(writer.inner.0, b"dea");
+            writer.flush().unwrap();
+            assert_eq!(writer.into_inner().0, b"dead");
+
+            // Fails after accepting the first character of every write.
+            struct Failing(Vec<u8>);
+
+            impl Write for Failing {
+                fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                    if self.0.len() % 2 == 1 {
+                        return Err(std::io::ErrorKind::Other.into());
+                    }
+                    self.0.extend_from_slice(&buf[..1]);
+                    Ok(1)
+                }
+                fn flush(&mut self) -> Result<()> { Ok(()) }
+            }
+
+            let mut writer = HexEncodingWriter::new(Failing(Vec::new()), Lower);
+            assert_eq!(writer.write(&[0xde, 0xad]).unwrap(), 1);
+            assert!(writer.flush().is_err());
+            assert_eq!(writer.into_inner().0, b"d");
+
+            let mut writer = HexEncodingWriter::new(Partial(Vec::new()), Lower);
+            writer.write_all(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+            writer.flush().unwrap();
+            assert_eq!(writer.into_inner().0, b"deadbeef");
+        }
     }
 }
@@ -4,8 +4,6 @@
 
 use core::fmt;
 
-use crate::write_err;
-
 /// Formats error.
 ///
 /// If `std` feature is OFF appends error source (delimited by `: `). We do this because
@@ -28,6 +26,21 @@ macro_rules! write_err {
     }
 }
 
+/// The category of a hex decoding error, without its details.
+///
+/// Returned by [`HexToBytesError::kind`] and [`HexToArrayError::kind`], this is useful when only
+/// the category matters e.g., for logging or metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Non-hexadecimal character.
+    InvalidChar,
+    /// Purported hex string had odd length.
+    OddLength,
+    /// Hex string had the wrong length for a fixed-length type.
+    InvalidLength,
+}
+
 /// Hex decoding error.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HexToBytesError {
@@ -37,6 +50,28 @@ pub enum HexToBytesError {
     OddLengthString(OddLengthStringError),
 }
 
+impl HexToBytesError {
+    /// Returns the category of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use hex_conservative::error::ErrorKind;
+    /// use hex_conservative::FromHex;
+    ///
+    /// let err = Vec::<u8>::from_hex("abc").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::OddLength);
+    /// # }
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            HexToBytesError::InvalidChar(_) => ErrorKind::InvalidChar,
+            HexToBytesError::OddLengthString(_) => ErrorKind::OddLength,
+        }
+    }
+}
+
 impl fmt::Display for HexToBytesError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use HexToBytesError::*;
@@ -72,6 +107,12 @@ impl From<OddLengthStringError> for HexToBytesError {
 }
 
 /// Invalid hex character.
+///
+/// The error stores the invalid byte and its byte position because several decoding paths never
+/// see a string, e.g. [`HexToBytesIter::from_ascii_bytes`](crate::HexToBytesIter::from_ascii_bytes)
+/// and [`decode_in_place`](crate::decode_in_place). Use
+/// [`invalid_char_in`](Self::invalid_char_in) to recover the full character and its position in
+/// characters from the string that was decoded.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InvalidCharError {
     pub(crate) invalid: u8,
@@ -79,15 +120,131 @@ pub struct InvalidCharError {
 }
 
 impl InvalidCharError {
+    /// Constructs a new `InvalidCharError` for the `invalid` byte found at position `pos`.
+    pub fn new(invalid: u8, pos: usize) -> Self { Self { invalid, pos } }
     /// Returns the invalid character byte.
     pub fn invalid_char(&self) -> u8 { self.invalid }
     /// Returns the position of the invalid character byte.
     pub fn pos(&self) -> usize { self.pos }
+
+    /// Returns the invalid character byte as a `char`.
+    ///
+    /// Only a single byte of a multi-byte UTF-8 character is stored so non-ASCII bytes are returned
+    /// as [`char::REPLACEMENT_CHARACTER`]. Use [`invalid_char_in`](Self::invalid_char_in) to
+    /// recover the actual character from the input.
+    pub fn invalid_char_lossy(&self) -> char {
+        if self.invalid.is_ascii() {
+            char::from(self.invalid)
+        } else {
+            char::REPLACEMENT_CHARACTER
+        }
+    }
+
+    /// Returns the full invalid character and its position counted in characters, given the
+    /// `input` string that was decoded.
+    ///
+    /// Hex is decoded from pairs of bytes so this error only stores the invalid byte and its byte
+    /// position. If that byte is part of a multi-byte UTF-8 sequence this recovers the whole
+    /// character from the original input.
+    ///
+    /// Returns `None` if `input` is not the string that produced this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let input = "ab«00";
+    /// let mut iter = HexToBytesIter::new(input).expect("even length");
+    /// let err = iter.nth(1).unwrap().unwrap_err();
+    /// assert_eq!(err.pos(), 2);
+    /// assert_eq!(err.invalid_char_in(input), Some(('«', 2)));
+    /// ```
+    pub fn invalid_char_in(&self, input: &str) -> Option<(char, usize)> {
+        if input.as_bytes().get(self.pos) != Some(&self.invalid) {
+            return None;
+        }
+        let mut start = self.pos;
+        while !input.is_char_boundary(start) {
+            start -= 1;
+        }
+        let c = input[start..].chars().next()?;
+        Some((c, input[..start].chars().count()))
+    }
+
+    /// Returns a displayable snippet of `input` around the invalid character.
+    ///
+    /// The invalid character is enclosed in brackets and at most `radius` characters are shown on
+    /// each side, `...` marks that the input continues. Multi-byte characters are never split. If
+    /// `input` is not the string that produced this error the snippet is still rendered around
+    /// the stored position, clamped to the end of `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let input = "0123deadGeef4567";
+    /// let mut iter = HexToBytesIter::new(input).expect("even length");
+    /// let err = iter.find_map(Result::err).expect("invalid char");
+    /// assert_eq!(format!("{}", err.context(input, 4)), "...dead[G]eef4...");
+    /// assert_eq!(format!("{}", err.context(input, 10)), "0123dead[G]eef4567");
+    /// ```
+    pub fn context<'a>(&self, input: &'a str, radius: usize) -> CharContext<'a> {
+        let mut start = core::cmp::min(self.pos, input.len());
+        while !input.is_char_boundary(start) {
+            start -= 1;
+        }
+        let end = input[start..].chars().next().map_or(start, |c| start + c.len_utf8());
+        let before_start = match radius {
+            0 => start,
+            n => input[..start].char_indices().rev().nth(n - 1).map_or(0, |(i, _)| i),
+        };
+        let after_end =
+            input[end..].char_indices().nth(radius).map_or(input.len(), |(i, _)| end + i);
+        CharContext {
+            before: &input[before_start..start],
+            invalid: &input[start..end],
+            after: &input[end..after_end],
+            truncated_start: before_start > 0,
+            truncated_end: after_end < input.len(),
+        }
+    }
+}
+
+/// A snippet of hex input around an invalid character.
+///
+/// Created by [`InvalidCharError::context`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharContext<'a> {
+    before: &'a str,
+    invalid: &'a str,
+    after: &'a str,
+    truncated_start: bool,
+    truncated_end: bool,
+}
+
+impl fmt::Display for CharContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.truncated_start {
+            f.write_str("...")?;
+        }
+        write!(f, "{}[{}]{}", self.before, self.invalid, self.after)?;
+        if self.truncated_end {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for InvalidCharError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "invalid hex char {} at pos {}", self.invalid, self.pos)
+        if self.invalid.is_ascii_graphic() {
+            write!(f, "invalid hex char '{}' at pos {}", char::from(self.invalid), self.pos)
+        } else {
+            // Likely part of a multi-byte character, display the raw byte.
+            write!(f, "invalid hex char byte 0x{:02x} at pos {}", self.invalid, self.pos)
+        }
     }
 }
 
@@ -105,11 +262,19 @@ pub struct OddLengthStringError {
 impl OddLengthStringError {
     /// Returns the odd length of the input string.
     pub fn length(&self) -> usize { self.len }
+
+    /// Returns the position of the last digit, which has no pair, in the input string.
+    pub fn unpaired_pos(&self) -> usize { self.len - 1 }
 }
 
 impl fmt::Display for OddLengthStringError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "odd hex string length {}", self.len)
+        write!(
+            f,
+            "odd hex string length {}, the character at position {} has no pair",
+            self.len,
+            self.unpaired_pos()
+        )
     }
 }
 
@@ -118,6 +283,68 @@ impl std::error::Error for OddLengthStringError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
 }
 
+/// Purported hex string had an odd number of hex digits.
+///
+/// Returned instead of [`OddLengthStringError`] when decoding skips characters such as whitespace,
+/// so the number of digits is not the length of the input string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OddDigitCountError {
+    pub(crate) digits: usize,
+    pub(crate) pos: usize,
+}
+
+impl OddDigitCountError {
+    /// Returns the odd number of hex digits in the input string.
+    pub fn digits(&self) -> usize { self.digits }
+
+    /// Returns the position of the last digit, which has no pair, in the input string.
+    pub fn unpaired_pos(&self) -> usize { self.pos }
+}
+
+impl fmt::Display for OddDigitCountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "odd number of hex digits {}, the digit at pos {} has no pair",
+            self.digits, self.pos
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OddDigitCountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// Decoded byte length was not a multiple of the requested chunk length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidChunkLengthError {
+    pub(crate) len: usize,
+    pub(crate) chunk_len: usize,
+}
+
+impl InvalidChunkLengthError {
+    /// Returns the number of bytes the input decodes to.
+    pub fn length(&self) -> usize { self.len }
+    /// Returns the chunk length in bytes that the decoded length should be a multiple of.
+    pub fn chunk_length(&self) -> usize { self.chunk_len }
+}
+
+impl fmt::Display for InvalidChunkLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "decoded length {} is not a multiple of the chunk length {}",
+            self.len, self.chunk_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidChunkLengthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
 /// Hex decoding error.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HexToArrayError {
@@ -127,6 +354,51 @@ pub enum HexToArrayError {
     InvalidLength(InvalidLengthError),
 }
 
+impl HexToArrayError {
+    /// Returns the category of this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::error::ErrorKind;
+    /// use hex_conservative::FromHex;
+    ///
+    /// let err = <[u8; 2]>::from_hex("abc").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidLength);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match *self {
+            HexToArrayError::InvalidChar(_) => ErrorKind::InvalidChar,
+            HexToArrayError::InvalidLength(_) => ErrorKind::InvalidLength,
+        }
+    }
+
+    /// Converts this error into the error returned when decoding to a vector, if possible.
+    ///
+    /// Returns `Some` for [`HexToArrayError::InvalidChar`] and `None` for
+    /// [`HexToArrayError::InvalidLength`] because decoding to a vector has no expected length,
+    /// only odd lengths are errors there. This is useful in generic code that decodes to both
+    /// arrays and vectors and wants a single error type for invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::{FromHex, HexToBytesError};
+    ///
+    /// let err = <[u8; 2]>::from_hex("abcg").unwrap_err();
+    /// assert!(matches!(err.into_bytes_error(), Some(HexToBytesError::InvalidChar(_))));
+    ///
+    /// let err = <[u8; 2]>::from_hex("ab").unwrap_err();
+    /// assert!(err.into_bytes_error().is_none());
+    /// ```
+    pub fn into_bytes_error(self) -> Option<HexToBytesError> {
+        match self {
+            HexToArrayError::InvalidChar(e) => Some(HexToBytesError::InvalidChar(e)),
+            HexToArrayError::InvalidLength(_) => None,
+        }
+    }
+}
+
 impl fmt::Display for HexToArrayError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use HexToArrayError::*;
@@ -168,11 +440,53 @@ pub struct InvalidLengthError {
     pub expected: usize,
     /// The invalid length.
     pub invalid: usize,
+    /// The expected number of decoded bytes, not counting any prefix in the lengths.
+    pub(crate) bytes: usize,
+}
+
+impl InvalidLengthError {
+    /// Constructs a new `InvalidLengthError` for an `invalid` length when `expected` was required.
+    ///
+    /// The lengths are counted in hex characters, so `expected / 2` bytes were expected.
+    pub fn new(invalid: usize, expected: usize) -> Self {
+        Self { expected, invalid, bytes: expected / 2 }
+    }
+    /// Returns the expected length.
+    pub fn expected_length(&self) -> usize { self.expected }
+    /// Returns the expected number of decoded bytes.
+    pub fn expected_bytes(&self) -> usize { self.bytes }
+    /// Returns the invalid length.
+    pub fn invalid_length(&self) -> usize { self.invalid }
+    /// Returns true if the input was longer than expected.
+    pub fn is_too_long(&self) -> bool { self.invalid > self.expected }
+    /// Returns true if the input was shorter than expected.
+    pub fn is_too_short(&self) -> bool { self.invalid < self.expected }
+
+    /// Returns the invalid length minus the expected length.
+    ///
+    /// Positive if the input was too long and negative if it was too short.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::error::InvalidLengthError;
+    ///
+    /// assert_eq!(InvalidLengthError::new(11, 8).difference(), 3);
+    /// assert_eq!(InvalidLengthError::new(4, 8).difference(), -4);
+    /// ```
+    pub fn difference(&self) -> isize {
+        // Lengths of strings and slices never exceed `isize::MAX`.
+        self.invalid as isize - self.expected as isize
+    }
 }
 
 impl fmt::Display for InvalidLengthError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "invilad hex string length {} (expected {})", self.invalid, self.expected)
+        write!(
+            f,
+            "invalid hex string length {} (expected {} characters for {} bytes)",
+            self.invalid, self.expected, self.bytes
+        )
     }
 }
 
@@ -180,3 +494,539 @@ impl fmt::Display for InvalidLengthError {
 impl std::error::Error for InvalidLengthError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
 }
+
+/// Tried to display a slice as an array of a different length.
+///
+/// Returned by [`DisplayArray::try_new`](crate::display::DisplayArray::try_new), the lengths are
+/// counted in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SliceLengthError {
+    pub(crate) len: usize,
+    pub(crate) expected: usize,
+}
+
+impl SliceLengthError {
+    /// Returns the length of the slice.
+    pub fn length(&self) -> usize { self.len }
+    /// Returns the length of the array.
+    pub fn expected_length(&self) -> usize { self.expected }
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid slice length {} (expected {})", self.len, self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceLengthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// The output buffer was too small to hold the encoded hex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityError {
+    pub(crate) required: usize,
+    pub(crate) available: usize,
+}
+
+impl CapacityError {
+    /// Returns the number of bytes required to hold the output.
+    pub fn required(&self) -> usize { self.required }
+    /// Returns the number of bytes that were available.
+    pub fn available(&self) -> usize { self.available }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "insufficient buffer capacity {} (required {})", self.available, self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// Error parsing a [`Case`](crate::Case) from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseCaseError;
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid hex case, expected \"lower\" or \"upper\"")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// Hex decoding error while decoding into a bounded buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexToArrayVecError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Purported hex string had odd length.
+    OddLengthString(OddLengthStringError),
+    /// The decoded bytes would not fit the buffer.
+    Capacity(CapacityError),
+}
+
+impl fmt::Display for HexToArrayVecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use HexToArrayVecError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to create bytes from hex"; e),
+            OddLengthString(ref e) =>
+                write_err!(f, "odd length, failed to create bytes from hex"; e),
+            Capacity(ref e) => write_err!(f, "too long, failed to create bytes from hex"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexToArrayVecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use HexToArrayVecError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            OddLengthString(ref e) => Some(e),
+            Capacity(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidCharError> for HexToArrayVecError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+impl From<OddLengthStringError> for HexToArrayVecError {
+    #[inline]
+    fn from(e: OddLengthStringError) -> Self { Self::OddLengthString(e) }
+}
+
+impl From<CapacityError> for HexToArrayVecError {
+    #[inline]
+    fn from(e: CapacityError) -> Self { Self::Capacity(e) }
+}
+
+impl From<HexToBytesError> for HexToArrayVecError {
+    #[inline]
+    fn from(e: HexToBytesError) -> Self {
+        match e {
+            HexToBytesError::InvalidChar(e) => Self::InvalidChar(e),
+            HexToBytesError::OddLengthString(e) => Self::OddLengthString(e),
+        }
+    }
+}
+
+/// Hex string started with a `0x` or `0X` prefix where none was expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedPrefixError {
+    pub(crate) prefix: &'static str,
+}
+
+impl UnexpectedPrefixError {
+    /// Returns the prefix found at the start of the input, `"0x"` or `"0X"`.
+    pub fn prefix(&self) -> &'static str { self.prefix }
+}
+
+impl fmt::Display for UnexpectedPrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected prefix {} in hex string", self.prefix)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnexpectedPrefixError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// Hex decoding error for input that must not have a `0x` prefix.
+///
+/// Returned by [`decode::to_vec_strict`](crate::decode::to_vec_strict).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexToBytesStrictError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Purported hex string had odd length.
+    OddLengthString(OddLengthStringError),
+    /// Hex string started with a `0x` or `0X` prefix.
+    UnexpectedPrefix(UnexpectedPrefixError),
+}
+
+impl fmt::Display for HexToBytesStrictError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use HexToBytesStrictError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to create bytes from hex"; e),
+            OddLengthString(ref e) =>
+                write_err!(f, "odd length, failed to create bytes from hex"; e),
+            UnexpectedPrefix(ref e) =>
+                write_err!(f, "prefixed, failed to create bytes from hex"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexToBytesStrictError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use HexToBytesStrictError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            OddLengthString(ref e) => Some(e),
+            UnexpectedPrefix(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidCharError> for HexToBytesStrictError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+impl From<OddLengthStringError> for HexToBytesStrictError {
+    #[inline]
+    fn from(e: OddLengthStringError) -> Self { Self::OddLengthString(e) }
+}
+
+impl From<UnexpectedPrefixError> for HexToBytesStrictError {
+    #[inline]
+    fn from(e: UnexpectedPrefixError) -> Self { Self::UnexpectedPrefix(e) }
+}
+
+impl From<HexToBytesError> for HexToBytesStrictError {
+    #[inline]
+    fn from(e: HexToBytesError) -> Self {
+        match e {
+            HexToBytesError::InvalidChar(e) => Self::InvalidChar(e),
+            HexToBytesError::OddLengthString(e) => Self::OddLengthString(e),
+        }
+    }
+}
+
+/// Hex string contained both lower and upper case digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedCaseError {
+    pub(crate) invalid: u8,
+    pub(crate) pos: usize,
+}
+
+impl MixedCaseError {
+    /// Returns the first digit whose case differs from the preceding alphabetic digits.
+    pub fn invalid_char(&self) -> u8 { self.invalid }
+    /// Returns the position of the digit whose case differs.
+    pub fn pos(&self) -> usize { self.pos }
+}
+
+impl fmt::Display for MixedCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "digit {} at pos {} has a different case than the preceding digits",
+            char::from(self.invalid),
+            self.pos
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MixedCaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { None }
+}
+
+/// Error returned by [`detect_case`](crate::detect_case).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DetectCaseError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Hex string contained both lower and upper case digits.
+    MixedCase(MixedCaseError),
+}
+
+impl fmt::Display for DetectCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DetectCaseError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to detect hex case"; e),
+            MixedCase(ref e) => write_err!(f, "mixed case, failed to detect hex case"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DetectCaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DetectCaseError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            MixedCase(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidCharError> for DetectCaseError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+impl From<MixedCaseError> for DetectCaseError {
+    #[inline]
+    fn from(e: MixedCaseError) -> Self { Self::MixedCase(e) }
+}
+
+/// Hex decoding error for input that must use a single case.
+///
+/// Returned by [`decode::to_vec_single_case`](crate::decode::to_vec_single_case).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SingleCaseError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Purported hex string had odd length.
+    OddLengthString(OddLengthStringError),
+    /// Hex string contained both lower and upper case digits.
+    MixedCase(MixedCaseError),
+}
+
+impl fmt::Display for SingleCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SingleCaseError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to create bytes from hex"; e),
+            OddLengthString(ref e) =>
+                write_err!(f, "odd length, failed to create bytes from hex"; e),
+            MixedCase(ref e) => write_err!(f, "mixed case, failed to create bytes from hex"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SingleCaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use SingleCaseError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            OddLengthString(ref e) => Some(e),
+            MixedCase(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidCharError> for SingleCaseError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+impl From<OddLengthStringError> for SingleCaseError {
+    #[inline]
+    fn from(e: OddLengthStringError) -> Self { Self::OddLengthString(e) }
+}
+
+impl From<MixedCaseError> for SingleCaseError {
+    #[inline]
+    fn from(e: MixedCaseError) -> Self { Self::MixedCase(e) }
+}
+
+/// Hex decoding error for input decoded with [`DecodeOptions`](crate::DecodeOptions).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeOptionsError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Purported hex string had an odd number of digits.
+    OddDigitCount(OddDigitCountError),
+    /// Hex string contained both lower and upper case digits.
+    MixedCase(MixedCaseError),
+}
+
+impl fmt::Display for DecodeOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodeOptionsError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to create bytes from hex"; e),
+            OddDigitCount(ref e) =>
+                write_err!(f, "odd number of digits, failed to create bytes from hex"; e),
+            MixedCase(ref e) => write_err!(f, "mixed case, failed to create bytes from hex"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeOptionsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodeOptionsError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            OddDigitCount(ref e) => Some(e),
+            MixedCase(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidCharError> for DecodeOptionsError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+impl From<OddDigitCountError> for DecodeOptionsError {
+    #[inline]
+    fn from(e: OddDigitCountError) -> Self { Self::OddDigitCount(e) }
+}
+
+impl From<MixedCaseError> for DecodeOptionsError {
+    #[inline]
+    fn from(e: MixedCaseError) -> Self { Self::MixedCase(e) }
+}
+
+/// Error decoding hex into an [`std::io::Write`] sink.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum DecodeToWriterError {
+    /// Non-hexadecimal character.
+    InvalidChar(InvalidCharError),
+    /// Purported hex string had odd length.
+    OddLengthString(OddLengthStringError),
+    /// Writing the decoded bytes failed.
+    Io(std::io::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for DecodeToWriterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DecodeToWriterError::*;
+
+        match *self {
+            InvalidChar(ref e) => write_err!(f, "invalid char, failed to decode hex"; e),
+            OddLengthString(ref e) => write_err!(f, "odd length, failed to decode hex"; e),
+            Io(ref e) => write_err!(f, "failed to write decoded bytes"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeToWriterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DecodeToWriterError::*;
+
+        match *self {
+            InvalidChar(ref e) => Some(e),
+            OddLengthString(ref e) => Some(e),
+            Io(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<InvalidCharError> for DecodeToWriterError {
+    #[inline]
+    fn from(e: InvalidCharError) -> Self { Self::InvalidChar(e) }
+}
+
+#[cfg(feature = "std")]
+impl From<OddLengthStringError> for DecodeToWriterError {
+    #[inline]
+    fn from(e: OddLengthStringError) -> Self { Self::OddLengthString(e) }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for DecodeToWriterError {
+    #[inline]
+    fn from(e: std::io::Error) -> Self { Self::Io(e) }
+}
+
+/// Error decoding hex into a fixed size array in constant time.
+///
+/// Returned by [`decode_to_array_ct`](crate::decode_to_array_ct). The position and value of
+/// invalid characters are not reported because finding them would leak timing information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexToArrayCtError {
+    /// The input contains at least one non-hexadecimal character.
+    InvalidChar,
+    /// Tried to parse fixed-length hash from a string with the wrong length.
+    InvalidLength(InvalidLengthError),
+}
+
+impl fmt::Display for HexToArrayCtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use HexToArrayCtError::*;
+
+        match *self {
+            InvalidChar => f.write_str("failed to parse hex, invalid hex char"),
+            InvalidLength(ref e) => write_err!(f, "failed to parse hex"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexToArrayCtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use HexToArrayCtError::*;
+
+        match *self {
+            InvalidChar => None,
+            InvalidLength(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<InvalidLengthError> for HexToArrayCtError {
+    #[inline]
+    fn from(e: InvalidLengthError) -> Self { Self::InvalidLength(e) }
+}
+
+/// Errors which can be adjusted after decoding input with a stripped `0x` prefix.
+pub(crate) trait WithPrefixLen {
+    /// Makes the positions and lengths in this error refer to the input including a prefix of
+    /// `len` characters.
+    fn with_prefix_len(self, len: usize) -> Self;
+}
+
+impl WithPrefixLen for InvalidCharError {
+    #[inline]
+    fn with_prefix_len(self, len: usize) -> Self { Self { pos: self.pos + len, ..self } }
+}
+
+impl WithPrefixLen for OddLengthStringError {
+    #[inline]
+    fn with_prefix_len(self, len: usize) -> Self { Self { len: self.len + len } }
+}
+
+impl WithPrefixLen for InvalidLengthError {
+    #[inline]
+    fn with_prefix_len(self, len: usize) -> Self {
+        Self { invalid: self.invalid + len, expected: self.expected + len, ..self }
+    }
+}
+
+impl WithPrefixLen for HexToBytesError {
+    #[inline]
+    fn with_prefix_len(self, len: usize) -> Self {
+        match self {
+            Self::InvalidChar(e) => Self::InvalidChar(e.with_prefix_len(len)),
+            Self::OddLengthString(e) => Self::OddLengthString(e.with_prefix_len(len)),
+        }
+    }
+}
+
+impl WithPrefixLen for HexToArrayError {
+    #[inline]
+    fn with_prefix_len(self, len: usize) -> Self {
+        match self {
+            Self::InvalidChar(e) => Self::InvalidChar(e.with_prefix_len(len)),
+            Self::InvalidLength(e) => Self::InvalidLength(e.with_prefix_len(len)),
+        }
+    }
+}
@@ -5,22 +5,27 @@
 use core::borrow::Borrow;
 use core::convert::TryInto;
 use core::iter::FusedIterator;
-use core::str;
+use core::{fmt, str};
 #[cfg(feature = "std")]
 use std::io;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use crate::alloc::vec::Vec;
-use crate::error::{InvalidCharError, OddLengthStringError};
+use crate::alloc::{string::String, vec::Vec};
+use crate::error::{
+    CapacityError, InvalidCharError, InvalidChunkLengthError, OddLengthStringError, WithPrefixLen,
+};
 use crate::{Case, Table};
 
 /// Convenience alias for `HexToBytesIter<HexDigitsIter<'a>>`.
 pub type HexSliceToBytesIter<'a> = HexToBytesIter<HexDigitsIter<'a>>;
 
 /// Iterator yielding bytes decoded from an iterator of pairs of hex digits.
+#[derive(Clone)]
 pub struct HexToBytesIter<T: Iterator<Item = [u8; 2]>> {
     iter: T,
     original_len: usize,
+    /// Length of a stripped prefix, added to error positions.
+    prefix_len: usize,
 }
 
 impl<'a> HexToBytesIter<HexDigitsIter<'a>> {
@@ -31,10 +36,49 @@ impl<'a> HexToBytesIter<HexDigitsIter<'a>> {
     /// If the input string is of odd length.
     #[inline]
     pub fn new(s: &'a str) -> Result<Self, OddLengthStringError> {
-        if s.len() % 2 != 0 {
-            Err(OddLengthStringError { len: s.len() })
+        Self::from_ascii_bytes(s.as_bytes())
+    }
+
+    /// Constructs a new `HexToBytesIter` from a string slice with an optional `0x` or `0X` prefix.
+    ///
+    /// A single leading prefix is stripped before decoding. Error positions and lengths refer to
+    /// the input string including the prefix.
+    ///
+    /// # Errors
+    ///
+    /// If the input string is of odd length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let iter = HexToBytesIter::new_stripping_prefix("0xdead").expect("even length");
+    /// assert_eq!(iter.collect::<Result<Vec<_>, _>>(), Ok(vec![0xde, 0xad]));
+    /// ```
+    #[inline]
+    pub fn new_stripping_prefix(s: &'a str) -> Result<Self, OddLengthStringError> {
+        let stripped = crate::strip_prefix(s);
+        let prefix_len = s.len() - stripped.len();
+        let mut iter = Self::new(stripped).map_err(|e| e.with_prefix_len(prefix_len))?;
+        iter.prefix_len = prefix_len;
+        Ok(iter)
+    }
+
+    /// Constructs a new `HexToBytesIter` from a slice of ASCII hex digits.
+    ///
+    /// This avoids validating the input as UTF-8 first, any byte that is not a hex digit is
+    /// reported as an [`InvalidCharError`] during iteration.
+    ///
+    /// # Errors
+    ///
+    /// If the input slice is of odd length.
+    #[inline]
+    pub fn from_ascii_bytes(bytes: &'a [u8]) -> Result<Self, OddLengthStringError> {
+        if bytes.len() % 2 != 0 {
+            Err(OddLengthStringError { len: bytes.len() })
         } else {
-            Ok(Self::new_unchecked(s))
+            Ok(Self::from_pairs(HexDigitsIter::new_unchecked(bytes)))
         }
     }
 
@@ -62,38 +106,156 @@ impl<'a> HexToBytesIter<HexDigitsIter<'a>> {
         }
         Ok(())
     }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> HexToBytesIter<T> {
+    /// Constructs a custom hex decoding iterator from another iterator.
+    #[inline]
+    pub fn from_pairs(iter: T) -> Self { Self { original_len: iter.len(), iter, prefix_len: 0 } }
+
+    /// Returns the position in the input of a hex char of the pair at `index`.
+    #[inline]
+    fn char_pos(&self, index: usize, is_high: bool) -> usize {
+        self.prefix_len + index * 2 + usize::from(!is_high)
+    }
+
+    /// Extends `target` with the bytes yielded by this `HexToBytesIter`.
+    ///
+    /// This avoids allocating an intermediate vector when concatenating decoded fragments.
+    ///
+    /// # Errors
+    ///
+    /// If this `HexToBytesIter` yields an `InvalidCharError`. The bytes decoded before the
+    /// invalid char have already been added to `target` in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let mut v = vec![0x01];
+    /// HexToBytesIter::new("dead").expect("even length").extend_into(&mut v).expect("valid hex");
+    /// HexToBytesIter::new("beef").expect("even length").extend_into(&mut v).expect("valid hex");
+    /// assert_eq!(v, [0x01, 0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    pub fn extend_into<E: Extend<u8>>(self, target: &mut E) -> Result<(), InvalidCharError> {
+        let mut error = None;
+        target.extend(self.map_while(|res| match res {
+            Ok(byte) => Some(byte),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }));
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Returns an iterator yielding each decoded byte together with its index in the output.
+    ///
+    /// The index counts decoded bytes from the front of the input, the first hex char of the
+    /// byte is at position `2 * index` in the input string. Decoding continues after an invalid
+    /// char so all invalid pairs can be reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let iter = HexToBytesIter::new("abxy01zz").expect("even length");
+    /// let invalid = iter
+    ///     .enumerate_positions()
+    ///     .filter_map(|(index, res)| res.err().map(|e| (index, e.pos())))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(invalid, [(1, 2), (3, 6)]);
+    /// ```
+    #[inline]
+    pub fn enumerate_positions(self) -> EnumeratePositions<T> { EnumeratePositions { iter: self } }
+
+    /// Returns an iterator yielding `[u8; N]` arrays starting from the end of the input.
+    ///
+    /// Like [`slice::rchunks`] the chunks are yielded back to front while the bytes within each
+    /// chunk keep their order. This is useful to decode the last of several fixed-size fields
+    /// first. The returned iterator is exact-size and fused, and yields the same chunks as
+    /// [`HexToArraysIter`] in reverse order. Chunks containing an invalid character yield the
+    /// error with the lowest position in that chunk.
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes is not a multiple of `N`. A short chunk is never yielded since
+    /// there would be no way to tell whether the short chunk belongs at the front.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let iter = HexToBytesIter::new("deadbeefcafe").expect("even length");
+    /// let mut chunks = iter.rchunks_array::<2>().expect("length is a multiple of 2");
+    /// assert_eq!(chunks.next(), Some(Ok([0xca, 0xfe])));
+    /// assert_eq!(chunks.next(), Some(Ok([0xbe, 0xef])));
+    /// assert_eq!(chunks.next(), Some(Ok([0xde, 0xad])));
+    /// assert_eq!(chunks.next(), None);
+    ///
+    /// let iter = HexToBytesIter::new("deadbeef").expect("even length");
+    /// assert!(iter.rchunks_array::<3>().is_err());
+    /// ```
+    #[inline]
+    pub fn rchunks_array<const N: usize>(
+        self,
+    ) -> Result<core::iter::Rev<HexToArraysIter<T, N>>, InvalidChunkLengthError>
+    where
+        T: DoubleEndedIterator,
+    {
+        HexToArraysIter::new(self).map(Iterator::rev)
+    }
 
     /// Writes all the bytes yielded by this `HexToBytesIter` to a `Vec<u8>`.
     ///
     /// This is equivalent to the combinator chain `iter().map().collect()` but was found by
     /// benchmarking to be faster.
+    ///
+    /// # Errors
+    ///
+    /// If this `HexToBytesIter` yields an `InvalidCharError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::HexToBytesIter;
+    ///
+    /// let pairs = [[b'd', b'e'], [b'a', b'd']];
+    /// let iter = HexToBytesIter::from_pairs(pairs.iter().copied());
+    /// assert_eq!(iter.collect_to_vec(), Ok(vec![0xde, 0xad]));
+    /// ```
     #[cfg(any(test, feature = "std", feature = "alloc"))]
-    pub(crate) fn drain_to_vec(self) -> Result<Vec<u8>, InvalidCharError> {
+    pub fn collect_to_vec(self) -> Result<Vec<u8>, InvalidCharError> {
         let len = self.len();
         let mut ret = Vec::with_capacity(len);
         let mut ptr = ret.as_mut_ptr();
-        for byte in self {
-            // SAFETY: for loop iterates `len` times, and `ret` has a capacity of at least `len`
+        let mut written = 0;
+        // `ExactSizeIterator` is a safe trait so `len` may be wrong, never write more than `len`.
+        for byte in self.take(len) {
+            // SAFETY: the loop iterates at most `len` times, and `ret` has a capacity of at least
+            // `len`
             unsafe {
                 // docs: "`core::ptr::write` is appropriate for initializing uninitialized memory"
                 core::ptr::write(ptr, byte?);
                 ptr = ptr.add(1);
             }
+            written += 1;
         }
-        // SAFETY: `len` elements have been initialized, and `ret` has a capacity of at least `len`
+        // SAFETY: `written` elements have been initialized, and `written <= len <= capacity`
         unsafe {
-            ret.set_len(len);
+            ret.set_len(written);
         }
         Ok(ret)
     }
 }
 
-impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> HexToBytesIter<T> {
-    /// Constructs a custom hex decoding iterator from another iterator.
-    #[inline]
-    pub fn from_pairs(iter: T) -> Self { Self { original_len: iter.len(), iter } }
-}
-
 impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> Iterator for HexToBytesIter<T> {
     type Item = Result<u8, InvalidCharError>;
 
@@ -102,11 +264,7 @@ impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> Iterator for HexToBytesIte
         let [hi, lo] = self.iter.next()?;
         Some(hex_chars_to_byte(hi, lo).map_err(|(c, is_high)| InvalidCharError {
             invalid: c,
-            pos: if is_high {
-                (self.original_len - self.iter.len() - 1) * 2
-            } else {
-                (self.original_len - self.iter.len() - 1) * 2 + 1
-            },
+            pos: self.char_pos(self.original_len - self.iter.len() - 1, is_high),
         }))
     }
 
@@ -118,11 +276,7 @@ impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> Iterator for HexToBytesIte
         let [hi, lo] = self.iter.nth(n)?;
         Some(hex_chars_to_byte(hi, lo).map_err(|(c, is_high)| InvalidCharError {
             invalid: c,
-            pos: if is_high {
-                (self.original_len - self.iter.len() - 1) * 2
-            } else {
-                (self.original_len - self.iter.len() - 1) * 2 + 1
-            },
+            pos: self.char_pos(self.original_len - self.iter.len() - 1, is_high),
         }))
     }
 }
@@ -135,7 +289,7 @@ impl<T: Iterator<Item = [u8; 2]> + DoubleEndedIterator + ExactSizeIterator> Doub
         let [hi, lo] = self.iter.next_back()?;
         Some(hex_chars_to_byte(hi, lo).map_err(|(c, is_high)| InvalidCharError {
             invalid: c,
-            pos: if is_high { self.iter.len() * 2 } else { self.iter.len() * 2 + 1 },
+            pos: self.char_pos(self.iter.len(), is_high),
         }))
     }
 
@@ -144,13 +298,34 @@ impl<T: Iterator<Item = [u8; 2]> + DoubleEndedIterator + ExactSizeIterator> Doub
         let [hi, lo] = self.iter.nth_back(n)?;
         Some(hex_chars_to_byte(hi, lo).map_err(|(c, is_high)| InvalidCharError {
             invalid: c,
-            pos: if is_high { self.iter.len() * 2 } else { self.iter.len() * 2 + 1 },
+            pos: self.char_pos(self.iter.len(), is_high),
         }))
     }
 }
 
 impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> ExactSizeIterator for HexToBytesIter<T> {}
 
+/// Shows the remaining undecoded hex and the position of the next hex char without consuming the
+/// iterator.
+///
+/// The position is counted from the front, like the positions of errors yielded by `next`.
+///
+/// ```
+/// use hex_conservative::HexToBytesIter;
+///
+/// let mut iter = HexToBytesIter::new("deadbeef").expect("even length");
+/// iter.next();
+/// assert_eq!(format!("{:?}", iter), r#"HexToBytesIter { remaining: "adbeef", pos: 2 }"#);
+/// ```
+impl fmt::Debug for HexToBytesIter<HexDigitsIter<'_>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HexToBytesIter")
+            .field("remaining", &RemainingDigits(&self.iter.iter))
+            .field("pos", &self.char_pos(self.original_len - self.iter.len(), true))
+            .finish()
+    }
+}
+
 impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator + FusedIterator> FusedIterator
     for HexToBytesIter<T>
 {
@@ -176,11 +351,145 @@ impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator + FusedIterator> io::Read
     }
 }
 
+/// Iterator yielding decoded bytes together with their index in the output.
+///
+/// Created by [`HexToBytesIter::enumerate_positions`].
+pub struct EnumeratePositions<T: Iterator<Item = [u8; 2]>> {
+    iter: HexToBytesIter<T>,
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> Iterator for EnumeratePositions<T> {
+    type Item = (usize, Result<u8, InvalidCharError>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.iter.original_len - self.iter.len();
+        self.iter.next().map(|res| (index, res))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator> ExactSizeIterator for EnumeratePositions<T> {}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator + FusedIterator> FusedIterator
+    for EnumeratePositions<T>
+{
+}
+
+/// Iterator yielding fixed-size byte arrays decoded from a [`HexToBytesIter`].
+///
+/// This is useful for parsing a concatenated sequence of fixed-size records from a single hex
+/// string without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{HexToArraysIter, HexToBytesIter};
+///
+/// let bytes = HexToBytesIter::new("deadbeefcafebabe").expect("even length");
+/// let mut iter = HexToArraysIter::<_, 4>::new(bytes).expect("length is a multiple of 4");
+/// assert_eq!(iter.next(), Some(Ok([0xde, 0xad, 0xbe, 0xef])));
+/// assert_eq!(iter.next(), Some(Ok([0xca, 0xfe, 0xba, 0xbe])));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Clone)]
+pub struct HexToArraysIter<T: Iterator<Item = [u8; 2]>, const N: usize> {
+    iter: HexToBytesIter<T>,
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator, const N: usize> HexToArraysIter<T, N> {
+    /// Constructs a new `HexToArraysIter` yielding `[u8; N]` arrays from a `HexToBytesIter`.
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes yielded by `iter` is not a multiple of `N`.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is zero.
+    #[inline]
+    pub fn new(iter: HexToBytesIter<T>) -> Result<Self, InvalidChunkLengthError> {
+        assert!(N > 0, "chunk length must be non-zero");
+        if iter.len() % N != 0 {
+            Err(InvalidChunkLengthError { len: iter.len(), chunk_len: N })
+        } else {
+            Ok(Self { iter })
+        }
+    }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator, const N: usize> Iterator
+    for HexToArraysIter<T, N>
+{
+    type Item = Result<[u8; N], InvalidCharError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut ret = [0u8; N];
+        let mut err = None;
+        // Always consume a full chunk, even on error, so that subsequent chunks stay aligned.
+        for dst in &mut ret {
+            match self.iter.next()? {
+                Ok(byte) => *dst = byte,
+                Err(e) => {
+                    err.get_or_insert(e);
+                }
+            }
+        }
+        match err {
+            Some(e) => Some(Err(e)),
+            None => Some(Ok(ret)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator, const N: usize> ExactSizeIterator
+    for HexToArraysIter<T, N>
+{
+    #[inline]
+    fn len(&self) -> usize { self.iter.len() / N }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + DoubleEndedIterator + ExactSizeIterator, const N: usize>
+    DoubleEndedIterator for HexToArraysIter<T, N>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut ret = [0u8; N];
+        let mut err = None;
+        // Fill from the back, keep the last error seen which is the one at the lowest position.
+        for dst in ret.iter_mut().rev() {
+            match self.iter.next_back()? {
+                Ok(byte) => *dst = byte,
+                Err(e) => err = Some(e),
+            }
+        }
+        match err {
+            Some(e) => Some(Err(e)),
+            None => Some(Ok(ret)),
+        }
+    }
+}
+
+impl<T: Iterator<Item = [u8; 2]> + ExactSizeIterator + FusedIterator, const N: usize> FusedIterator
+    for HexToArraysIter<T, N>
+{
+}
+
 /// An internal iterator returning hex digits from a string.
 ///
 /// Generally you shouldn't need to refer to this or bother with it and just use
 /// [`HexToBytesIter::new`] consuming the returned value and use `HexSliceToBytesIter` if you need
 /// to refer to the iterator in your types.
+#[derive(Clone)]
 pub struct HexDigitsIter<'a> {
     // Invariant: the length of the chunks is 2.
     // Technically, this is `iter::Map` but we can't use it because fn is anonymous.
@@ -224,17 +533,97 @@ impl DoubleEndedIterator for HexDigitsIter<'_> {
 
 impl ExactSizeIterator for HexDigitsIter<'_> {}
 
+impl fmt::Debug for HexDigitsIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("HexDigitsIter").field(&RemainingDigits(&self.iter)).finish()
+    }
+}
+
+/// Formats the remaining digits of a `HexDigitsIter` as a quoted string.
+///
+/// Bytes that are not ASCII are escaped as `\xNN` because a pair of digits may split a multi-byte
+/// character.
+struct RemainingDigits<'a, 'b>(&'b core::slice::ChunksExact<'a, u8>);
+
+impl fmt::Debug for RemainingDigits<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for byte in self.0.clone().flatten() {
+            if byte.is_ascii() {
+                for c in char::from(*byte).escape_debug() {
+                    f.write_char(c)?;
+                }
+            } else {
+                write!(f, "\\x{:02x}", byte)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
 impl core::iter::FusedIterator for HexDigitsIter<'_> {}
 
+/// Marks bytes that are not hex digits in [`HEX_DIGITS`].
+const INVALID: u8 = 0xff;
+
+/// Maps each byte to the value of the hex digit it represents or to [`INVALID`].
+static HEX_DIGITS: [u8; 256] = {
+    let mut table = [INVALID; 256];
+    let mut c = 0;
+    while c < 256 {
+        if let Some(value) = crate::const_hex_digit(c as u8) {
+            table[c] = value;
+        }
+        c += 1;
+    }
+    table
+};
+
+/// Returns the value of the hex digit `c` represents or `None` if `c` is not a hex digit.
+#[inline]
+pub(crate) fn hex_digit(c: u8) -> Option<u8> {
+    match HEX_DIGITS[usize::from(c)] {
+        INVALID => None,
+        value => Some(value),
+    }
+}
+
+/// Returns the value of the hex digit `c` represents and its case, `None` for the digits `0-9`.
+#[inline]
+pub(crate) fn hex_digit_with_case(c: u8) -> Option<(u8, Option<Case>)> {
+    let value = hex_digit(c)?;
+    let case = match value {
+        0..=9 => None,
+        // ASCII letters only differ in this bit.
+        _ if c & 0x20 != 0 => Some(Case::Lower),
+        _ => Some(Case::Upper),
+    };
+    Some((value, case))
+}
+
+/// Checks that all bytes in `hex` are hex digits, reporting the first one that isn't.
+#[inline]
+pub(crate) fn check_hex_digits(hex: &[u8]) -> Result<(), InvalidCharError> {
+    match hex.iter().position(|&c| hex_digit(c).is_none()) {
+        Some(pos) => Err(InvalidCharError { invalid: hex[pos], pos }),
+        None => Ok(()),
+    }
+}
+
 /// `hi` and `lo` are bytes representing hex characters.
 ///
 /// Returns the valid byte or the invalid input byte and a bool indicating error for `hi` or `lo`.
-fn hex_chars_to_byte(hi: u8, lo: u8) -> Result<u8, (u8, bool)> {
-    let hih = (hi as char).to_digit(16).ok_or((hi, true))?;
-    let loh = (lo as char).to_digit(16).ok_or((lo, false))?;
-
-    let ret = (hih << 4) + loh;
-    Ok(ret as u8)
+#[inline]
+pub(crate) fn hex_chars_to_byte(hi: u8, lo: u8) -> Result<u8, (u8, bool)> {
+    let hih = HEX_DIGITS[usize::from(hi)];
+    let loh = HEX_DIGITS[usize::from(lo)];
+    // Only valid digits are below 16, a single check covers both on the happy path.
+    if (hih | loh) & 0xf0 != 0 {
+        return Err(if hih == INVALID { (hi, true) } else { (lo, false) });
+    }
+    Ok((hih << 4) | loh)
 }
 
 /// Iterator over bytes which encodes the bytes and yields hex characters.
@@ -245,10 +634,16 @@ where
 {
     /// The iterator whose next byte will be encoded to yield hex characters.
     iter: I,
+    /// The high character of the pair (high, low), pending while the separator is yielded.
+    high: Option<char>,
     /// The low character of the pair (high, low) of hex characters encoded per byte.
     low: Option<char>,
     /// The byte-to-hex conversion table.
     table: &'static Table,
+    /// The character yielded between the encodings of consecutive bytes.
+    sep: Option<char>,
+    /// True once at least one byte has been encoded.
+    started: bool,
 }
 
 impl<I> BytesToHexIter<I>
@@ -259,7 +654,100 @@ where
     /// Constructs a `BytesToHexIter` that will yield hex characters in the given case from a byte
     /// iterator.
     pub fn new(iter: I, case: Case) -> BytesToHexIter<I> {
-        Self { iter, low: None, table: case.table() }
+        Self { iter, high: None, low: None, table: case.table(), sep: None, started: false }
+    }
+
+    /// Constructs a `BytesToHexIter` that will yield hex characters in the given case from a byte
+    /// iterator, yielding `sep` between the encodings of consecutive bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::{BytesToHexIter, Case};
+    ///
+    /// let bytes = [0xde, 0xad, 0xbe, 0xef];
+    /// let s = BytesToHexIter::with_separator(bytes.iter(), Case::Lower, ':').collect::<String>();
+    /// assert_eq!(s, "de:ad:be:ef");
+    /// ```
+    pub fn with_separator(iter: I, case: Case, sep: char) -> BytesToHexIter<I> {
+        Self { iter, high: None, low: None, table: case.table(), sep: Some(sep), started: false }
+    }
+
+    /// Returns the number of characters yielded for `n` remaining bytes of the inner iterator.
+    #[inline]
+    fn char_count(&self, n: usize) -> usize {
+        let (digits, seps) = self.counts(n);
+        digits + seps
+    }
+
+    /// Returns the number of hex digits and separators yielded for `n` remaining bytes of the
+    /// inner iterator.
+    #[inline]
+    fn counts(&self, n: usize) -> (usize, usize) {
+        let pending = usize::from(self.high.is_some()) + usize::from(self.low.is_some());
+        let seps = match self.sep {
+            Some(_) if self.started => n,
+            Some(_) => n.saturating_sub(1),
+            None => 0,
+        };
+        (n * 2 + pending, seps)
+    }
+
+    /// Encodes `byte`, returning the next character and storing the remaining ones.
+    #[inline]
+    fn encode(&mut self, byte: u8) -> char {
+        let [high, low] = self.table.byte_to_chars(byte);
+        self.low = Some(low);
+        match self.sep {
+            Some(sep) if self.started => {
+                self.high = Some(high);
+                sep
+            }
+            _ => {
+                self.started = true;
+                high
+            }
+        }
+    }
+}
+
+impl<I> BytesToHexIter<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<u8>,
+{
+    /// Writes all the hex characters yielded by this `BytesToHexIter` to the provided slice as
+    /// ASCII bytes, returning the number of bytes written.
+    ///
+    /// A non-ASCII separator is written UTF-8 encoded.
+    ///
+    /// # Errors
+    ///
+    /// If `out` is too small to hold the output, nothing is written in this case.
+    pub fn encode_to_slice(self, out: &mut [u8]) -> Result<usize, CapacityError> {
+        let (digits, seps) = self.counts(self.iter.len());
+        let sep_len = self.sep.map_or(0, char::len_utf8);
+        let required = digits + seps * sep_len;
+        if out.len() < required {
+            return Err(CapacityError { required, available: out.len() });
+        }
+        let mut pos = 0;
+        for c in self {
+            pos += c.encode_utf8(&mut out[pos..]).len();
+        }
+        Ok(pos)
+    }
+
+    /// Collects all the hex characters yielded by this `BytesToHexIter` into a `String`.
+    ///
+    /// This is equivalent to `iter.collect::<String>()` but allocates the exact capacity upfront.
+    #[cfg(any(test, feature = "std", feature = "alloc"))]
+    pub fn collect_to_string(self) -> String {
+        let mut ret = String::with_capacity(self.len());
+        for c in self {
+            ret.push(c);
+        }
+        ret
     }
 }
 
@@ -272,26 +760,22 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<char> {
-        match self.low {
-            Some(c) => {
-                self.low = None;
-                Some(c)
+        if let Some(c) = self.high.take() {
+            return Some(c);
+        }
+        match self.low.take() {
+            Some(c) => Some(c),
+            None => {
+                let byte = *self.iter.next()?.borrow();
+                Some(self.encode(byte))
             }
-            None => self.iter.next().map(|b| {
-                let [high, low] = self.table.byte_to_chars(*b.borrow());
-                self.low = Some(low);
-                high
-            }),
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (min, max) = self.iter.size_hint();
-        match self.low {
-            Some(_) => (min * 2 + 1, max.map(|max| max * 2 + 1)),
-            None => (min * 2, max.map(|max| max * 2)),
-        }
+        (self.char_count(min), max.map(|max| self.char_count(max)))
     }
 }
 
@@ -302,16 +786,15 @@ where
 {
     #[inline]
     fn next_back(&mut self) -> Option<char> {
-        match self.low {
-            Some(c) => {
-                self.low = None;
-                Some(c)
+        if let Some(c) = self.high.take() {
+            return Some(c);
+        }
+        match self.low.take() {
+            Some(c) => Some(c),
+            None => {
+                let byte = *self.iter.next_back()?.borrow();
+                Some(self.encode(byte))
             }
-            None => self.iter.next_back().map(|b| {
-                let [high, low] = self.table.byte_to_chars(*b.borrow());
-                self.low = Some(low);
-                high
-            }),
         }
     }
 }
@@ -322,7 +805,7 @@ where
     I::Item: Borrow<u8>,
 {
     #[inline]
-    fn len(&self) -> usize { self.iter.len() * 2 }
+    fn len(&self) -> usize { self.char_count(self.iter.len()) }
 }
 
 impl<I> FusedIterator for BytesToHexIter<I>
@@ -332,10 +815,259 @@ where
 {
 }
 
+/// Iterator over bytes read from an [`io::Read`] which encodes the bytes and yields hex characters.
+///
+/// This is the encoding counterpart of the `io::Read` implementation of [`HexToBytesIter`], large
+/// inputs can be encoded lazily without reading them into memory first. Reads are buffered
+/// internally so the reader doesn't need to be wrapped in a `BufReader`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{Case, ReadToHexIter};
+///
+/// let reader: &[u8] = &[0xde, 0xad, 0xbe, 0xef];
+/// let s = ReadToHexIter::new(reader, Case::Lower).collect::<Result<String, _>>().expect("read");
+/// assert_eq!(s, "deadbeef");
+/// ```
+#[cfg(feature = "std")]
+pub struct ReadToHexIter<R: io::Read> {
+    /// The reader whose bytes will be encoded to yield hex characters.
+    reader: R,
+    /// Bytes read from `reader` but not yet encoded.
+    buf: [u8; 512],
+    /// Position of the next byte to encode in `buf`.
+    pos: usize,
+    /// Number of valid bytes in `buf`.
+    len: usize,
+    /// The low character of the pair (high, low) of hex characters encoded per byte.
+    low: Option<char>,
+    /// The byte-to-hex conversion table.
+    table: &'static Table,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> ReadToHexIter<R> {
+    /// Constructs a `ReadToHexIter` that will yield hex characters in the given case from a
+    /// reader.
+    pub fn new(reader: R, case: Case) -> Self {
+        Self { reader, buf: [0; 512], pos: 0, len: 0, low: None, table: case.table() }
+    }
+
+    /// Returns the inner reader.
+    ///
+    /// Bytes that have been read but not yet encoded are lost.
+    pub fn into_inner(self) -> R { self.reader }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> Iterator for ReadToHexIter<R> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(low) = self.low.take() {
+            return Some(Ok(low));
+        }
+        while self.pos == self.len {
+            match self.reader.read(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(len) => {
+                    self.pos = 0;
+                    self.len = len;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        let [high, low] = self.table.byte_to_chars(self.buf[self.pos]);
+        self.pos += 1;
+        self.low = Some(low);
+        Some(Ok(high))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = (self.len - self.pos) * 2 + usize::from(self.low.is_some());
+        (pending, None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_to_bytes_extend_into() {
+        let mut v = vec![0x01];
+        HexToBytesIter::new("dead").unwrap().extend_into(&mut v).unwrap();
+        HexToBytesIter::new("").unwrap().extend_into(&mut v).unwrap();
+        assert_eq!(v, [0x01, 0xde, 0xad]);
+
+        let mut d = std::collections::VecDeque::new();
+        HexToBytesIter::new("beef").unwrap().extend_into(&mut d).unwrap();
+        assert_eq!(d, [0xbe, 0xef]);
+
+        let mut v = Vec::new();
+        assert_eq!(
+            HexToBytesIter::new("deadgeef").unwrap().extend_into(&mut v),
+            Err(InvalidCharError { invalid: b'g', pos: 4 })
+        );
+        assert_eq!(v, [0xde, 0xad]);
+    }
+
+    #[test]
+    fn hex_chars_to_byte_matches_to_digit() {
+        for hi in 0..=255u8 {
+            for lo in 0..=255u8 {
+                let expected = match ((hi as char).to_digit(16), (lo as char).to_digit(16)) {
+                    (Some(h), Some(l)) => Ok((h << 4 | l) as u8),
+                    (None, _) => Err((hi, true)),
+                    (Some(_), None) => Err((lo, false)),
+                };
+                assert_eq!(hex_chars_to_byte(hi, lo), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn hex_to_bytes_rchunks_array() {
+        let iter = HexToBytesIter::new("000102030405").unwrap();
+        let chunks = iter.rchunks_array::<3>().unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.collect::<Vec<_>>(), [Ok([0x03, 0x04, 0x05]), Ok([0x00, 0x01, 0x02])]);
+
+        let iter = HexToBytesIter::new("").unwrap();
+        assert_eq!(iter.rchunks_array::<2>().unwrap().next(), None);
+
+        let iter = HexToBytesIter::new("000102").unwrap();
+        assert!(iter.rchunks_array::<2>().is_err());
+
+        // The error at the lowest position in the chunk is reported, same as `HexToArraysIter`.
+        let iter = HexToBytesIter::new("00xy02zz").unwrap();
+        let mut chunks = iter.rchunks_array::<2>().unwrap();
+        assert_eq!(chunks.next(), Some(Err(InvalidCharError { invalid: b'z', pos: 6 })));
+        assert_eq!(chunks.next(), Some(Err(InvalidCharError { invalid: b'x', pos: 2 })));
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn hex_to_bytes_clone() {
+        let mut iter = HexToBytesIter::new("deadbeef").unwrap();
+        assert_eq!(iter.next(), Some(Ok(0xde)));
+        // Validate a copy first, then consume the original.
+        assert!(iter.clone().all(|res| res.is_ok()));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(Ok(0xef)));
+
+        let mut arrays =
+            HexToArraysIter::<_, 2>::new(HexToBytesIter::new("deadbeef").unwrap()).unwrap();
+        assert_eq!(arrays.clone().count(), 2);
+        assert_eq!(arrays.next_back(), Some(Ok([0xbe, 0xef])));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_to_hex_iter() {
+        let reader: &[u8] = &[];
+        assert_eq!(ReadToHexIter::new(reader, Case::Lower).next().map(|r| r.unwrap()), None);
+
+        let bytes = [0xde, 0xad, 0xbe, 0xef].repeat(200);
+        let got = ReadToHexIter::new(&bytes[..], Case::Upper).collect::<io::Result<String>>();
+        assert_eq!(got.unwrap(), "DEADBEEF".repeat(200));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_to_hex_iter_error() {
+        /// Yields one byte, is interrupted once then fails.
+        struct Failing(usize);
+
+        impl io::Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0 += 1;
+                match self.0 {
+                    1 => {
+                        buf[0] = 0xab;
+                        Ok(1)
+                    }
+                    2 => Err(io::ErrorKind::Interrupted.into()),
+                    _ => Err(io::ErrorKind::Other.into()),
+                }
+            }
+        }
+
+        let mut iter = ReadToHexIter::new(Failing(0), Case::Lower);
+        assert_eq!(iter.size_hint(), (0, None));
+        assert_eq!(iter.next().unwrap().unwrap(), 'a');
+        assert_eq!(iter.size_hint(), (1, None));
+        assert_eq!(iter.next().unwrap().unwrap(), 'b');
+        assert_eq!(iter.next().unwrap().unwrap_err().kind(), io::ErrorKind::Other);
+    }
+
+    #[test]
+    fn hex_to_bytes_enumerate_positions() {
+        let mut iter = HexToBytesIter::new("abgc01dh").unwrap().enumerate_positions();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some((0, Ok(0xab))));
+        assert_eq!(iter.next(), Some((1, Err(InvalidCharError { invalid: b'g', pos: 2 }))));
+        assert_eq!(iter.next(), Some((2, Ok(0x01))));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((3, Err(InvalidCharError { invalid: b'h', pos: 7 }))));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = HexToBytesIter::new("").unwrap().enumerate_positions();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn hex_to_bytes_iter_stripping_prefix() {
+        let mut iter = HexToBytesIter::new_stripping_prefix("0xdead").unwrap();
+        assert_eq!(iter.next(), Some(Ok(0xde)));
+        assert_eq!(iter.next(), Some(Ok(0xad)));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(HexToBytesIter::new_stripping_prefix("0Xab").unwrap().next(), Some(Ok(0xab)));
+        assert_eq!(HexToBytesIter::new_stripping_prefix("ab").unwrap().next(), Some(Ok(0xab)));
+        assert_eq!(HexToBytesIter::new_stripping_prefix("0x").unwrap().len(), 0);
+        // Only a single prefix is stripped.
+        assert_eq!(
+            HexToBytesIter::new_stripping_prefix("0x0xab").unwrap().next(),
+            Some(Err(InvalidCharError { invalid: b'x', pos: 3 }))
+        );
+        assert_eq!(
+            HexToBytesIter::new_stripping_prefix("0xabc").unwrap_err(),
+            OddLengthStringError { len: 5 }
+        );
+        // Positions refer to the input including the prefix from both ends.
+        let mut iter = HexToBytesIter::new_stripping_prefix("0xgaag").unwrap();
+        assert_eq!(iter.next(), Some(Err(InvalidCharError { invalid: b'g', pos: 2 })));
+        let mut iter = HexToBytesIter::new_stripping_prefix("0xgaag").unwrap();
+        assert_eq!(iter.next_back(), Some(Err(InvalidCharError { invalid: b'g', pos: 5 })));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_to_bytes_iter_debug() {
+        let mut iter = HexToBytesIter::new("dead«00").unwrap();
+        assert_eq!(
+            format!("{:?}", iter),
+            r#"HexToBytesIter { remaining: "dead\xc2\xab00", pos: 0 }"#
+        );
+        iter.next();
+        iter.next();
+        assert_eq!(format!("{:?}", iter), r#"HexToBytesIter { remaining: "\xc2\xab00", pos: 4 }"#);
+        // Formatting doesn't consume the iterator.
+        assert_eq!(iter.len(), 2);
+        assert_eq!(format!("{:?}", iter.iter), r#"HexDigitsIter("\xc2\xab00")"#);
+        let mut prefixed = HexToBytesIter::new_stripping_prefix("0xdead").unwrap();
+        prefixed.next();
+        assert_eq!(format!("{:?}", prefixed), r#"HexToBytesIter { remaining: "ad", pos: 4 }"#);
+
+        iter.by_ref().for_each(drop);
+        assert_eq!(format!("{:?}", iter), r#"HexToBytesIter { remaining: "", pos: 8 }"#);
+    }
+
     #[test]
     fn encode_byte() {
         assert_eq!(Table::LOWER.byte_to_chars(0x00), ['0', '0']);
@@ -392,6 +1124,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_iter_ascii_bytes() {
+        let hex = b"deadbeef";
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        for (i, b) in HexToBytesIter::from_ascii_bytes(hex).unwrap().enumerate() {
+            assert_eq!(b.unwrap(), bytes[i]);
+        }
+
+        let mut iter = HexToBytesIter::from_ascii_bytes(b"de\xffdbeef").unwrap();
+        assert_eq!(iter.next(), Some(Ok(0xde)));
+        assert_eq!(iter.next(), Some(Err(InvalidCharError { invalid: 0xff, pos: 2 })));
+
+        assert_eq!(
+            HexToBytesIter::from_ascii_bytes(b"abc").err(),
+            Some(OddLengthStringError { len: 3 })
+        );
+    }
+
     #[test]
     fn hex_to_digits_size_hint() {
         let hex = "deadbeef";
@@ -417,7 +1168,7 @@ mod tests {
         assert_eq!(got, want);
 
         let hex = "";
-        let want = [];
+        let want: [u8; 0] = [];
         let iter = HexToBytesIter::new_unchecked(hex);
         let mut got = [];
         iter.drain_to_slice(&mut got).unwrap();
@@ -480,12 +1231,12 @@ mod tests {
         let hex = "deadbeef";
         let want = [0xde, 0xad, 0xbe, 0xef];
         let iter = HexToBytesIter::new_unchecked(hex);
-        let got = iter.drain_to_vec().unwrap();
+        let got = iter.collect_to_vec().unwrap();
         assert_eq!(got, want);
 
         let hex = "";
         let iter = HexToBytesIter::new_unchecked(hex);
-        let got = iter.drain_to_vec().unwrap();
+        let got = iter.collect_to_vec().unwrap();
         assert!(got.is_empty());
     }
 
@@ -493,21 +1244,75 @@ mod tests {
     fn hex_to_bytes_vec_drain_first_char_error() {
         let hex = "geadbeef";
         let iter = HexToBytesIter::new_unchecked(hex);
-        assert_eq!(iter.drain_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 0 }));
+        assert_eq!(iter.collect_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 0 }));
     }
 
     #[test]
     fn hex_to_bytes_vec_drain_middle_char_error() {
         let hex = "deadgeef";
         let iter = HexToBytesIter::new_unchecked(hex);
-        assert_eq!(iter.drain_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 4 }));
+        assert_eq!(iter.collect_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 4 }));
     }
 
     #[test]
     fn hex_to_bytes_vec_drain_end_char_error() {
         let hex = "deadbeeg";
         let iter = HexToBytesIter::new_unchecked(hex);
-        assert_eq!(iter.drain_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 7 }));
+        assert_eq!(iter.collect_to_vec(), Err(InvalidCharError { invalid: b'g', pos: 7 }));
+    }
+
+    #[test]
+    fn hex_to_bytes_vec_collect_from_pairs() {
+        let pairs = [[b'd', b'e'], [b'a', b'd'], [b'B', b'E']];
+        let iter = HexToBytesIter::from_pairs(pairs.iter().copied());
+        assert_eq!(iter.collect_to_vec(), Ok(vec![0xde, 0xad, 0xbe]));
+
+        // An iterator that reports fewer items than it yields.
+        struct Lying<I>(I);
+
+        impl<I: Iterator<Item = [u8; 2]>> Iterator for Lying<I> {
+            type Item = [u8; 2];
+            fn next(&mut self) -> Option<Self::Item> { self.0.next() }
+            fn size_hint(&self) -> (usize, Option<usize>) { (1, Some(1)) }
+        }
+
+        impl<I: Iterator<Item = [u8; 2]>> ExactSizeIterator for Lying<I> {}
+
+        let iter = HexToBytesIter::from_pairs(Lying(pairs.iter().copied()));
+        assert_eq!(iter.collect_to_vec(), Ok(vec![0xde]));
+    }
+
+    #[test]
+    fn hex_to_arrays() {
+        let hex = "deadbeefcafebabe";
+        let mut iter = HexToArraysIter::<_, 2>::new(HexToBytesIter::new(hex).unwrap()).unwrap();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(Ok([0xde, 0xad])));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Ok([0xbe, 0xef])));
+        assert_eq!(iter.next(), Some(Ok([0xca, 0xfe])));
+        assert_eq!(iter.next(), Some(Ok([0xba, 0xbe])));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let iter = HexToArraysIter::<_, 4>::new(HexToBytesIter::new("").unwrap()).unwrap();
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    fn hex_to_arrays_char_error() {
+        let hex = "deadgeefcafebabe";
+        let mut iter = HexToArraysIter::<_, 2>::new(HexToBytesIter::new(hex).unwrap()).unwrap();
+        assert_eq!(iter.next(), Some(Ok([0xde, 0xad])));
+        assert_eq!(iter.next(), Some(Err(InvalidCharError { invalid: b'g', pos: 4 })));
+        assert_eq!(iter.next(), Some(Ok([0xca, 0xfe])));
+    }
+
+    #[test]
+    fn hex_to_arrays_length_error() {
+        let hex = "deadbeefcafe";
+        let got = HexToArraysIter::<_, 4>::new(HexToBytesIter::new(hex).unwrap());
+        assert_eq!(got.err(), Some(InvalidChunkLengthError { len: 6, chunk_len: 4 }));
     }
 
     #[test]
@@ -524,6 +1329,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn encode_iter_collect_to_string() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        let got = BytesToHexIter::new(bytes.iter(), Case::Lower).collect_to_string();
+        assert_eq!(got, "deadbeef");
+        assert_eq!(got.capacity(), 8);
+        let got = BytesToHexIter::new(bytes.iter(), Case::Upper).collect_to_string();
+        assert_eq!(got, "DEADBEEF");
+        let got = BytesToHexIter::new([].iter(), Case::Lower).collect_to_string();
+        assert_eq!(got, "");
+    }
+
+    #[test]
+    fn encode_iter_with_separator() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        let iter = BytesToHexIter::with_separator(bytes.iter(), Case::Lower, ':');
+        assert_eq!(iter.len(), 11);
+        assert_eq!(iter.collect::<String>(), "de:ad:be:ef");
+        let iter = BytesToHexIter::with_separator(bytes.iter(), Case::Upper, ' ');
+        assert_eq!(iter.collect::<String>(), "DE AD BE EF");
+        let iter = BytesToHexIter::with_separator(bytes.iter(), Case::Lower, ':').rev();
+        assert_eq!(iter.collect::<String>(), "ef:be:ad:de");
+
+        let iter = BytesToHexIter::with_separator([0xab].iter(), Case::Lower, ':');
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<String>(), "ab");
+        let iter = BytesToHexIter::with_separator([].iter(), Case::Lower, ':');
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn encode_iter_with_separator_len() {
+        let bytes = [0xde, 0xad, 0xbe];
+        let mut iter = BytesToHexIter::with_separator(bytes.iter(), Case::Lower, ':');
+        for i in (0..=8).rev() {
+            assert_eq!(iter.len(), i);
+            assert_eq!(iter.size_hint(), (i, Some(i)));
+            let _ = iter.next();
+        }
+    }
+
+    #[test]
+    fn encode_iter_to_slice() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        let mut out = [0u8; 10];
+        let n = BytesToHexIter::new(bytes.iter(), Case::Lower).encode_to_slice(&mut out).unwrap();
+        assert_eq!(&out[..n], b"deadbeef");
+
+        let iter = BytesToHexIter::with_separator(bytes.iter(), Case::Upper, '-');
+        let mut out = [0u8; 11];
+        let n = iter.encode_to_slice(&mut out).unwrap();
+        assert_eq!(&out[..n], b"DE-AD-BE-EF");
+
+        let iter = BytesToHexIter::with_separator(bytes[..2].iter(), Case::Lower, '·');
+        let mut out = [0u8; 6];
+        let n = iter.encode_to_slice(&mut out).unwrap();
+        assert_eq!(core::str::from_utf8(&out[..n]).unwrap(), "de·ad");
+    }
+
+    #[test]
+    fn encode_iter_to_slice_error() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        let mut out = [0u8; 7];
+
+        let iter = BytesToHexIter::new(bytes.iter(), Case::Lower);
+        assert_eq!(
+            iter.encode_to_slice(&mut out),
+            Err(CapacityError { required: 8, available: 7 })
+        );
+        assert_eq!(out, [0u8; 7]);
+
+        let iter = BytesToHexIter::with_separator(bytes[..2].iter(), Case::Lower, '·');
+        let mut out = [0u8; 5];
+        assert_eq!(
+            iter.encode_to_slice(&mut out),
+            Err(CapacityError { required: 6, available: 5 })
+        );
+    }
+
     #[test]
     fn encode_iter_backwards() {
         let bytes = [0xde, 0xad, 0xbe, 0xef];
@@ -566,3 +1454,54 @@ mod tests {
         assert_eq!(upper_got, upper_want);
     }
 }
+
+#[cfg(bench)]
+mod benches {
+    use test::{black_box, Bencher};
+
+    use super::*;
+
+    /// The implementation of `hex_chars_to_byte` before it used [`HEX_DIGITS`], for comparison.
+    fn hex_chars_to_byte_to_digit(hi: u8, lo: u8) -> Result<u8, (u8, bool)> {
+        let hih = (hi as char).to_digit(16).ok_or((hi, true))?;
+        let loh = (lo as char).to_digit(16).ok_or((lo, false))?;
+        Ok(((hih << 4) + loh) as u8)
+    }
+
+    fn hex_input() -> Vec<u8> {
+        b"0123456789abcdefABCDEF".iter().copied().cycle().take(4096).collect()
+    }
+
+    #[bench]
+    pub fn hex_chars_to_byte_lookup(bh: &mut Bencher) {
+        let hex = hex_input();
+        bh.iter(|| {
+            for pair in hex.chunks_exact(2) {
+                black_box(hex_chars_to_byte(black_box(pair[0]), black_box(pair[1]))).unwrap();
+            }
+        });
+        bh.bytes = hex.len() as u64;
+    }
+
+    #[bench]
+    pub fn hex_chars_to_byte_char_to_digit(bh: &mut Bencher) {
+        let hex = hex_input();
+        bh.iter(|| {
+            for pair in hex.chunks_exact(2) {
+                black_box(hex_chars_to_byte_to_digit(black_box(pair[0]), black_box(pair[1])))
+                    .unwrap();
+            }
+        });
+        bh.bytes = hex.len() as u64;
+    }
+
+    #[bench]
+    pub fn hex_to_bytes_collect_to_vec(bh: &mut Bencher) {
+        let hex = hex_input();
+        let hex = core::str::from_utf8(&hex).unwrap();
+        bh.iter(|| {
+            black_box(HexToBytesIter::new(black_box(hex)).unwrap().collect_to_vec()).unwrap()
+        });
+        bh.bytes = hex.len() as u64;
+    }
+}
@@ -38,12 +38,16 @@
 // Experimental features we need.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(bench, feature(test))]
 // Coding conventions
 #![warn(missing_docs)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(bench)]
+extern crate test;
+
 #[doc(hidden)]
 pub mod _export {
     /// A re-export of core::*
@@ -52,11 +56,19 @@ pub mod _export {
     }
 }
 
+mod array;
 pub mod buf_encoder;
+pub mod decode;
+#[cfg(feature = "alloc")]
+mod decoder;
 pub mod display;
 pub mod error;
 mod iter;
+#[cfg(feature = "alloc")]
+mod options;
 pub mod parse;
+#[cfg(feature = "alloc")]
+pub mod round_trip;
 #[cfg(feature = "serde")]
 pub mod serde;
 
@@ -66,16 +78,831 @@ pub mod prelude {
     pub use crate::{display::DisplayHex, parse::FromHex};
 }
 
+use arrayvec::{ArrayString, ArrayVec};
 pub(crate) use table::Table;
 
+use crate::buf_encoder::GenericBufEncoder;
+#[cfg(feature = "std")]
+use crate::error::DecodeToWriterError;
+use crate::error::{
+    CapacityError, DetectCaseError, HexToArrayCtError, InvalidLengthError, MixedCaseError,
+    ParseCaseError, WithPrefixLen,
+};
+
 #[rustfmt::skip]                // Keep public re-exports separate.
 #[doc(inline)]
 pub use self::{
+    array::HexArray,
     display::DisplayHex,
-    error::{OddLengthStringError, HexToBytesError, HexToArrayError, InvalidCharError},
-    iter::{BytesToHexIter, HexToBytesIter, HexSliceToBytesIter},
+    error::{OddLengthStringError, HexToBytesError, HexToArrayError, HexToArrayVecError, InvalidCharError},
+    iter::{BytesToHexIter, EnumeratePositions, HexToArraysIter, HexToBytesIter, HexSliceToBytesIter},
     parse::FromHex,
 };
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::decoder::HexDecoder;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::iter::ReadToHexIter;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::options::DecodeOptions;
+
+/// Decodes anything that can be viewed as a `str` e.g., an owned `String`.
+///
+/// This is the same as [`FromHex::from_hex`] but avoids `&s` or `s.as_str()` churn in code that
+/// holds owned strings.
+///
+/// # Errors
+///
+/// Same as `T::from_hex`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::from_hex_str;
+///
+/// let s = String::from("deadbeef");
+/// let a = from_hex_str::<[u8; 4]>(s).expect("valid hex");
+/// assert_eq!(a, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn from_hex_str<T: FromHex>(s: impl AsRef<str>) -> Result<T, T::Error> {
+    T::from_hex(s.as_ref())
+}
+
+/// Decodes a hex string into a caller-provided buffer.
+///
+/// This is useful when decoding many hex strings of the same length because the buffer can be
+/// reused instead of allocating a new one for each string.
+///
+/// # Errors
+///
+/// - If the length of `hex` is not exactly twice the length of `buf` (this includes odd lengths).
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// The contents of `buf` are left untouched if an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// let mut buf = [0u8; 4];
+/// hex_conservative::decode_to_slice("deadbeef", &mut buf).expect("valid hex");
+/// assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn decode_to_slice(hex: &str, buf: &mut [u8]) -> Result<(), HexToArrayError> {
+    if hex.len() != buf.len() * 2 {
+        return Err(error::InvalidLengthError::new(hex.len(), buf.len() * 2).into());
+    }
+    // Validate before writing anything so that `buf` is not partially overwritten on error.
+    iter::check_hex_digits(hex.as_bytes())?;
+    // length checked above
+    HexToBytesIter::new_unchecked(hex).drain_to_slice(buf)?;
+    Ok(())
+}
+
+/// Decodes ASCII hex in `buf` into the first half of the same buffer.
+///
+/// Decoded output is half the size of the input and each byte is written at or before the
+/// position it was read from so no second buffer is needed. Returns the decoded sub-slice, the
+/// rest of `buf` is left as is.
+///
+/// # Errors
+///
+/// - If the length of `buf` is odd.
+/// - If `buf` contains a byte that is not an ASCII hex digit.
+///
+/// The contents of `buf` are left untouched if an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// let mut buf = *b"deadbeef";
+/// let decoded = hex_conservative::decode_in_place(&mut buf).expect("valid hex");
+/// assert_eq!(decoded, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn decode_in_place(buf: &mut [u8]) -> Result<&mut [u8], HexToBytesError> {
+    if buf.len() % 2 != 0 {
+        return Err(OddLengthStringError { len: buf.len() }.into());
+    }
+    // Validate before writing anything so that `buf` is not partially overwritten on error.
+    iter::check_hex_digits(buf)?;
+    let len = buf.len() / 2;
+    for i in 0..len {
+        // Output index `i` never exceeds input index `2 * i`, the input is read before writing.
+        buf[i] = iter::hex_chars_to_byte(buf[2 * i], buf[2 * i + 1]).expect("validated above");
+    }
+    Ok(&mut buf[..len])
+}
+
+/// Decodes a hex string into a fixed size array with the bytes in reverse order.
+///
+/// This parses hex displayed byte-wise backwards (e.g. using `#[display_backward(true)]` with
+/// [`impl_fmt_traits`]) back into the natural byte order.
+///
+/// # Errors
+///
+/// - If the length of `hex` is not exactly `N * 2`.
+/// - If `hex` contains a non-hexadecimal character, the position refers to the input string.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_to_array_rev;
+///
+/// assert_eq!(decode_to_array_rev::<4>("78563412"), Ok([0x12, 0x34, 0x56, 0x78]));
+/// ```
+pub fn decode_to_array_rev<const N: usize>(hex: &str) -> Result<[u8; N], HexToArrayError> {
+    let mut ret = <[u8; N]>::from_hex(hex)?;
+    ret.reverse();
+    Ok(ret)
+}
+
+/// Decodes a hex string into `out`, reusing its allocation.
+///
+/// `out` is cleared first and then the decoded bytes are appended. This is useful when decoding
+/// many hex strings in a loop, similar to how [`std::io::Read::read_to_end`] reuses a buffer.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd, `out` is left empty.
+/// - If `hex` contains a non-hexadecimal character. The bytes decoded before the invalid
+///   character are left in `out`.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_into_vec;
+///
+/// let mut buf = Vec::new();
+/// for hex in ["dead", "beef"] {
+///     decode_into_vec(hex, &mut buf).expect("valid hex");
+///     assert_eq!(buf.len(), 2);
+/// }
+/// assert_eq!(buf, [0xbe, 0xef]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_into_vec(hex: &str, out: &mut alloc::vec::Vec<u8>) -> Result<(), HexToBytesError> {
+    out.clear();
+    let iter = HexToBytesIter::new(hex)?;
+    out.reserve(iter.len());
+    Ok(iter.extend_into(out)?)
+}
+
+/// Decodes a hex string of variable length into a stack-allocated [`ArrayVec`].
+///
+/// This is useful in `no_std` environments without an allocator when the decoded length is not
+/// fixed but has a known upper bound.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If the decoded bytes would exceed `CAP`, the capacity is counted in bytes.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_to_arrayvec;
+///
+/// let v = decode_to_arrayvec::<8>("deadbeef").expect("valid hex that fits");
+/// assert_eq!(v.as_slice(), [0xde, 0xad, 0xbe, 0xef]);
+/// assert!(decode_to_arrayvec::<2>("deadbeef").is_err());
+/// ```
+pub fn decode_to_arrayvec<const CAP: usize>(
+    hex: &str,
+) -> Result<ArrayVec<u8, CAP>, HexToArrayVecError> {
+    let iter = HexToBytesIter::new(hex)?;
+    if iter.len() > CAP {
+        return Err(CapacityError { required: iter.len(), available: CAP }.into());
+    }
+    let mut ret = ArrayVec::new();
+    for byte in iter {
+        ret.push(byte?);
+    }
+    Ok(ret)
+}
+
+/// Decodes a hex string of up to `MAX` bytes into a fixed size array.
+///
+/// Returns the array together with the number of decoded bytes, the remaining bytes of the array
+/// are zero. This is useful for fixed storage which accepts a range of lengths, see
+/// [`decode_to_arrayvec`] if the length should be stored with the bytes.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If `hex` decodes to more than `MAX` bytes, the capacity is counted in bytes.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_to_array_partial;
+///
+/// let (array, len) = decode_to_array_partial::<4>("dead").expect("valid hex that fits");
+/// assert_eq!(array, [0xde, 0xad, 0x00, 0x00]);
+/// assert_eq!(len, 2);
+/// assert!(decode_to_array_partial::<1>("dead").is_err());
+/// ```
+pub fn decode_to_array_partial<const MAX: usize>(
+    hex: &str,
+) -> Result<([u8; MAX], usize), HexToArrayVecError> {
+    let iter = HexToBytesIter::new(hex)?;
+    let len = iter.len();
+    if len > MAX {
+        return Err(CapacityError { required: len, available: MAX }.into());
+    }
+    let mut ret = [0u8; MAX];
+    iter.drain_to_slice(&mut ret[..len])?;
+    Ok((ret, len))
+}
+
+/// Decodes a hex string and appends the bytes to an existing [`ArrayVec`].
+///
+/// This is useful when accumulating several hex fields into one fixed-capacity buffer. `out` is
+/// left unchanged if an error is returned.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If the decoded bytes would exceed the remaining capacity of `out`, counted in bytes.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use hex_conservative::decode_into_arrayvec;
+///
+/// let mut buf = ArrayVec::<u8, 4>::new();
+/// decode_into_arrayvec("dead", &mut buf).expect("valid hex that fits");
+/// decode_into_arrayvec("beef", &mut buf).expect("valid hex that fits");
+/// assert_eq!(buf.as_slice(), [0xde, 0xad, 0xbe, 0xef]);
+/// assert!(decode_into_arrayvec("00", &mut buf).is_err());
+/// ```
+pub fn decode_into_arrayvec<const CAP: usize>(
+    hex: &str,
+    out: &mut ArrayVec<u8, CAP>,
+) -> Result<(), HexToArrayVecError> {
+    let iter = HexToBytesIter::new(hex)?;
+    let available = out.remaining_capacity();
+    if iter.len() > available {
+        return Err(CapacityError { required: iter.len(), available }.into());
+    }
+    let original_len = out.len();
+    for byte in iter {
+        match byte {
+            Ok(byte) => out.push(byte),
+            Err(e) => {
+                out.truncate(original_len);
+                return Err(e.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decodes a hex string into a [`SmallVec`](smallvec::SmallVec).
+///
+/// Results of up to `N` bytes are kept inline, longer ones spill to the heap. This is useful when
+/// decoding many mostly-small strings.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_to_smallvec;
+///
+/// let v = decode_to_smallvec::<4>("deadbeef").expect("valid hex");
+/// assert_eq!(v.as_slice(), [0xde, 0xad, 0xbe, 0xef]);
+/// assert!(!v.spilled());
+/// assert!(decode_to_smallvec::<2>("deadbeef").expect("valid hex").spilled());
+/// ```
+#[cfg(feature = "smallvec")]
+pub fn decode_to_smallvec<const N: usize>(
+    hex: &str,
+) -> Result<smallvec::SmallVec<[u8; N]>, HexToBytesError> {
+    let iter = HexToBytesIter::new(hex)?;
+    let mut ret = smallvec::SmallVec::with_capacity(iter.len());
+    for byte in iter {
+        ret.push(byte?);
+    }
+    Ok(ret)
+}
+
+/// Encodes `bytes` as a lower-case hex string.
+///
+/// This is the same as [`DisplayHex::to_lower_hex_string`] but doesn't require importing the
+/// trait.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(hex_conservative::encode_lower(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_lower(bytes: &[u8]) -> alloc::string::String { bytes.to_lower_hex_string() }
+
+/// Encodes `bytes` as an upper-case hex string.
+///
+/// This is the same as [`DisplayHex::to_upper_hex_string`] but doesn't require importing the
+/// trait.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(hex_conservative::encode_upper(&[0xde, 0xad, 0xbe, 0xef]), "DEADBEEF");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_upper(bytes: &[u8]) -> alloc::string::String { bytes.to_upper_hex_string() }
+
+/// Encodes each of `items` as a hex string.
+///
+/// The output vector and each string are preallocated, this is useful e.g., when serializing a
+/// list of byte arrays.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{encode_many, Case};
+///
+/// let items = [&[0xde, 0xad][..], &[0xbe, 0xef][..]];
+/// assert_eq!(encode_many(items, Case::Lower), ["dead", "beef"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_many<'a, I>(items: I, case: Case) -> alloc::vec::Vec<alloc::string::String>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    // `collect` preallocates using the iterator's size hint, `to_hex_string` reserves using
+    // `hex_reserve_suggestion`.
+    items.into_iter().map(|item| item.to_hex_string(case)).collect()
+}
+
+/// Encodes the bytes yielded by `iter` as a hex string.
+///
+/// The string is preallocated using the lower bound of the iterator's size hint. This is a
+/// shorthand for collecting a [`BytesToHexIter`] for the common case of encoding a byte stream.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{hex_from_bytes, Case};
+///
+/// let bytes = (0xaa..=0xad).rev();
+/// assert_eq!(hex_from_bytes(bytes, Case::Upper), "ADACABAA");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hex_from_bytes<I: IntoIterator<Item = u8>>(iter: I, case: Case) -> alloc::string::String {
+    let iter = iter.into_iter();
+    let mut s = alloc::string::String::with_capacity(iter.size_hint().0.saturating_mul(2));
+    for byte in iter {
+        let [hi, lo] = encode_byte(byte, case);
+        s.push(char::from(hi));
+        s.push(char::from(lo));
+    }
+    s
+}
+
+/// Encodes `bytes` as hex into a stack-allocated [`ArrayString`].
+///
+/// This is useful in `no_std` environments without an allocator, the returned value derefs to
+/// `&str`. `HEX_LEN` is counted in hex chars and must be at least `bytes.len() * 2`.
+///
+/// # Errors
+///
+/// If the encoded hex would exceed `HEX_LEN`. The required and available capacity in the error
+/// are counted in bytes before encoding, same as
+/// [`BufEncoder::put_bytes_exact`](buf_encoder::BufEncoder::put_bytes_exact).
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{encode_to_array_str, Case};
+///
+/// let s = encode_to_array_str::<8>(&[0xde, 0xad, 0xbe, 0xef], Case::Lower).expect("fits");
+/// assert_eq!(&*s, "deadbeef");
+/// assert!(encode_to_array_str::<6>(&[0xde, 0xad, 0xbe, 0xef], Case::Lower).is_err());
+/// ```
+pub fn encode_to_array_str<const HEX_LEN: usize>(
+    bytes: &[u8],
+    case: Case,
+) -> Result<ArrayString<HEX_LEN>, CapacityError> {
+    let mut encoder = GenericBufEncoder::with_buf(ArrayString::new(), case);
+    encoder.put_bytes_exact(bytes)?;
+    Ok(encoder.into_buf())
+}
+
+/// Encodes a single byte as two ASCII hex digits.
+///
+/// This is the smallest building block for hand-rolled formats, e.g. percent-encoding.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{encode_byte, Case};
+///
+/// assert_eq!(encode_byte(0xaf, Case::Lower), *b"af");
+/// const UPPER: [u8; 2] = encode_byte(0x0c, Case::Upper);
+/// assert_eq!(UPPER, *b"0C");
+/// ```
+pub const fn encode_byte(byte: u8, case: Case) -> [u8; 2] {
+    match case {
+        Case::Lower => Table::LOWER.byte_to_ascii(byte),
+        Case::Upper => Table::UPPER.byte_to_ascii(byte),
+    }
+}
+
+/// Decodes a single byte from its two ASCII hex digits.
+///
+/// Upper and lower case digits are accepted. This is the building block used by the decoders in
+/// this crate, it is useful for hand-rolled formats that already split out the digits.
+///
+/// # Errors
+///
+/// If `hi` or `lo` is not a hex digit. The position in the error is `0` for `hi` and `1` for `lo`,
+/// `hi` is checked first.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_byte;
+///
+/// assert_eq!(decode_byte(b'a', b'F'), Ok(0xaf));
+/// assert_eq!(decode_byte(b'a', b'g').unwrap_err().pos(), 1);
+/// ```
+pub fn decode_byte(hi: u8, lo: u8) -> Result<u8, InvalidCharError> {
+    iter::hex_chars_to_byte(hi, lo).map_err(|(invalid, is_high)| InvalidCharError {
+        invalid,
+        pos: if is_high { 0 } else { 1 },
+    })
+}
+
+/// Returns the number of bytes `hex` decodes to, without looking at the characters.
+///
+/// This is a cheap check which allows rejecting oversized input before paying for a full
+/// [`validate`] or decode, e.g. in a request handler.
+///
+/// # Errors
+///
+/// If the length of `hex` is odd.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decoded_len;
+///
+/// assert_eq!(decoded_len("deadbeef"), Ok(4));
+/// assert!(decoded_len("deadbee").is_err());
+/// // Characters are not checked.
+/// assert_eq!(decoded_len("xx"), Ok(1));
+/// ```
+#[inline]
+pub fn decoded_len(hex: &str) -> Result<usize, OddLengthStringError> {
+    if hex.len() % 2 != 0 {
+        return Err(OddLengthStringError { len: hex.len() });
+    }
+    Ok(hex.len() / 2)
+}
+
+/// Checks that `hex` is valid hex without decoding it.
+///
+/// This is the same validation performed when decoding into a `Vec<u8>` but nothing is allocated
+/// or written. Upper, lower and mixed case digits are accepted.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd.
+/// - If `hex` contains a non-hexadecimal character, the first one is reported.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::validate;
+///
+/// assert!(validate("deadBEEF").is_ok());
+/// assert!(validate("deadbee").is_err());
+/// assert!(validate("deadbeeg").is_err());
+/// ```
+pub fn validate(hex: &str) -> Result<(), HexToBytesError> {
+    if hex.len() % 2 != 0 {
+        return Err(OddLengthStringError { len: hex.len() }.into());
+    }
+    Ok(iter::check_hex_digits(hex.as_bytes())?)
+}
+
+/// Returns true if `hex` is the hex encoding of `bytes`.
+///
+/// The hex digits are compared case-insensitively. Returns `false` if the lengths don't match or
+/// if `hex` contains a non-hexadecimal character. This doesn't allocate.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::eq_hex;
+///
+/// assert!(eq_hex(&[0xde, 0xad, 0xbe, 0xef], "deadBEEF"));
+/// assert!(!eq_hex(&[0xde, 0xad], "deadbeef"));
+/// assert!(!eq_hex(&[0xde, 0xad], "deag"));
+/// ```
+pub fn eq_hex(bytes: &[u8], hex: &str) -> bool {
+    hex.len() == bytes.len() * 2
+        && HexToBytesIter::new_unchecked(hex).zip(bytes).all(|(got, want)| got == Ok(*want))
+}
+
+/// Feeds the bytes `hex` decodes to into `hasher` without allocating.
+///
+/// This hashes the same as decoding to a `Vec<u8>` and calling [`Hash::hash`] on it provided that
+/// splitting a [`Hasher::write`] call into several calls doesn't change the result, which is the
+/// case for the hasher used by `std::collections::HashMap`. Useful e.g. for deduplicating
+/// hex-encoded blobs by content without materializing each of them.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd, nothing is hashed in this case.
+/// - If `hex` contains a non-hexadecimal character. The input is validated as it is hashed so some
+///   bytes may already have been fed into `hasher`.
+///
+/// [`Hash::hash`]: core::hash::Hash::hash
+/// [`Hasher::write`]: core::hash::Hasher::write
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+///
+/// use hex_conservative::hash_hex;
+///
+/// let mut from_hex = DefaultHasher::new();
+/// hash_hex("deadbeef", &mut from_hex).expect("valid hex");
+///
+/// let mut from_bytes = DefaultHasher::new();
+/// vec![0xde_u8, 0xad, 0xbe, 0xef].hash(&mut from_bytes);
+/// assert_eq!(from_hex.finish(), from_bytes.finish());
+/// ```
+pub fn hash_hex<H: core::hash::Hasher>(hex: &str, hasher: &mut H) -> Result<(), HexToBytesError> {
+    let iter = HexToBytesIter::new(hex)?;
+    // Same as the length prefix written by `<[u8] as Hash>::hash`.
+    hasher.write_usize(iter.len());
+    let mut buf = [0u8; 64];
+    let mut len = 0;
+    for byte in iter {
+        buf[len] = byte?;
+        len += 1;
+        if len == buf.len() {
+            hasher.write(&buf);
+            len = 0;
+        }
+    }
+    hasher.write(&buf[..len]);
+    Ok(())
+}
+
+/// Decodes a hex string and writes the bytes to `writer` as they are decoded.
+///
+/// The input is decoded in chunks so large inputs can be streamed to a file or socket without
+/// buffering all the decoded bytes. Only fully decoded chunks are written, if an invalid character
+/// is found bytes from preceding chunks may already have been written.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - If the length of `hex` is odd, nothing is written in this case.
+/// - If `hex` contains a non-hexadecimal character, the position refers to the input string.
+/// - If writing to `writer` fails.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = Vec::new();
+/// let n = hex_conservative::decode_to_writer("deadbeef", &mut out).expect("valid hex");
+/// assert_eq!(n, 4);
+/// assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_to_writer<W: std::io::Write>(
+    hex: &str,
+    writer: &mut W,
+) -> Result<usize, DecodeToWriterError> {
+    let mut iter = HexToBytesIter::new(hex)?;
+    let mut buf = [0u8; 512];
+    let mut written = 0;
+    while iter.len() > 0 {
+        let len = core::cmp::min(iter.len(), buf.len());
+        for dst in &mut buf[..len] {
+            *dst = iter.next().expect("len is less than remaining")?;
+        }
+        writer.write_all(&buf[..len])?;
+        written += len;
+    }
+    Ok(written)
+}
+
+/// Strips a single leading `0x` or `0X` from `hex` if present.
+fn strip_prefix(hex: &str) -> &str {
+    hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X")).unwrap_or(hex)
+}
+
+/// Decodes `hex` with `decode` after stripping a single leading `0x` or `0X` if present.
+///
+/// Positions and lengths in the returned error refer to `hex` including the prefix.
+pub(crate) fn decode_prefixed<T, E: WithPrefixLen>(
+    hex: &str,
+    decode: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, E> {
+    let stripped = strip_prefix(hex);
+    decode(stripped).map_err(|e| e.with_prefix_len(hex.len() - stripped.len()))
+}
+
+/// Decodes a hex string into a fixed size array at compile time.
+///
+/// This is intended for defining constants, invalid input causes a compile error when used in a
+/// `const` context.
+///
+/// # Panics
+///
+/// - If the length of `hex` is not exactly `N * 2`.
+/// - If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::const_decode_to_array;
+///
+/// const KEY: [u8; 4] = const_decode_to_array("deadbeef");
+/// assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub const fn const_decode_to_array<const N: usize>(hex: &str) -> [u8; N] {
+    let hex = hex.as_bytes();
+    if hex.len() != N * 2 {
+        panic!("invalid hex string length");
+    }
+    let mut ret = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        let hi = match const_hex_digit(hex[i * 2]) {
+            Some(digit) => digit,
+            None => panic!("invalid hex char"),
+        };
+        let lo = match const_hex_digit(hex[i * 2 + 1]) {
+            Some(digit) => digit,
+            None => panic!("invalid hex char"),
+        };
+        ret[i] = (hi << 4) | lo;
+        i += 1;
+    }
+    ret
+}
+
+/// Decodes a hex string into a fixed size array without branching on the input digits.
+///
+/// This is intended for secret material such as private keys. Hex digits are converted using
+/// arithmetic masking instead of lookups or comparisons and decoding does not stop at the first
+/// invalid character, validity is accumulated and checked once at the end. Only the length of
+/// `hex` (which is not secret) is checked up front.
+///
+/// Note that this is a best-effort mitigation, the compiler is free to emit branches anyway.
+///
+/// # Errors
+///
+/// - If the length of `hex` is not exactly `N * 2`.
+/// - If `hex` contains a non-hexadecimal character, the position is not reported.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::decode_to_array_ct;
+///
+/// let key = decode_to_array_ct::<4>("deadBEEF").expect("valid hex");
+/// assert_eq!(key, [0xde, 0xad, 0xbe, 0xef]);
+/// assert!(decode_to_array_ct::<4>("deadbeeg").is_err());
+/// ```
+pub fn decode_to_array_ct<const N: usize>(hex: &str) -> Result<[u8; N], HexToArrayCtError> {
+    let hex = hex.as_bytes();
+    if hex.len() != N * 2 {
+        return Err(InvalidLengthError::new(hex.len(), N * 2).into());
+    }
+    let mut ret = [0u8; N];
+    let mut invalid = 0u16;
+    for (byte, pair) in ret.iter_mut().zip(hex.chunks_exact(2)) {
+        let hi = ct_hex_digit(pair[0]);
+        let lo = ct_hex_digit(pair[1]);
+        invalid |= hi | lo;
+        *byte = ((hi << 4) | lo) as u8;
+    }
+    // Valid digits are less than 16, an invalid digit sets all bits.
+    if invalid >> 8 != 0 {
+        return Err(HexToArrayCtError::InvalidChar);
+    }
+    Ok(ret)
+}
+
+/// Returns the value of the hex digit `c` or `0xffff` if `c` is not a hex digit.
+///
+/// Each range check computes a mask that is all ones if `c` is in the range and zero otherwise,
+/// the start value is `-1` so exactly one matching range brings it to the digit value.
+fn ct_hex_digit(c: u8) -> u16 {
+    let c = i16::from(c);
+    let mut ret: i16 = -1;
+    // 0-9: if c > 0x2f && c < 0x3a { ret += c - 0x30 + 1 }
+    ret += (((0x2f - c) & (c - 0x3a)) >> 8) & (c - 0x2f);
+    // A-F: if c > 0x40 && c < 0x47 { ret += c - 0x41 + 10 + 1 }
+    ret += (((0x40 - c) & (c - 0x47)) >> 8) & (c - 0x36);
+    // a-f: if c > 0x60 && c < 0x67 { ret += c - 0x61 + 10 + 1 }
+    ret += (((0x60 - c) & (c - 0x67)) >> 8) & (c - 0x56);
+    ret as u16
+}
+
+/// Returns the value of the hex digit `c` or `None` if `c` is not a hex digit.
+pub(crate) const fn const_hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Detects the case of the alphabetic hex digits in `hex`.
+///
+/// Returns `Some(Case::Lower)` if all alphabetic digits are lowercase, `Some(Case::Upper)` if all
+/// alphabetic digits are uppercase and `None` if `hex` contains only the digits `0-9`.
+///
+/// # Errors
+///
+/// - If `hex` contains a non-hexadecimal character.
+/// - If `hex` contains mixed case digits, the first digit whose case differs from the case of the
+///   preceding alphabetic digits is reported.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::{detect_case, Case};
+///
+/// assert_eq!(detect_case("deadbeef"), Ok(Some(Case::Lower)));
+/// assert_eq!(detect_case("DEADBEEF"), Ok(Some(Case::Upper)));
+/// assert_eq!(detect_case("0123"), Ok(None));
+/// assert!(detect_case("DEADbeef").is_err());
+/// ```
+pub fn detect_case(hex: &str) -> Result<Option<Case>, DetectCaseError> {
+    let mut case = None;
+    for (pos, c) in hex.bytes().enumerate() {
+        let this = match iter::hex_digit_with_case(c) {
+            Some((_, Some(this))) => this,
+            Some((_, None)) => continue,
+            None => return Err(InvalidCharError { invalid: c, pos }.into()),
+        };
+        match case {
+            None => case = Some(this),
+            Some(case) if case != this => return Err(MixedCaseError { invalid: c, pos }.into()),
+            Some(_) => {}
+        }
+    }
+    Ok(case)
+}
+
+/// Normalizes `hex` to lowercase, allocating only if it contains uppercase digits.
+///
+/// The input is validated in the same pass, so a returned string always contains only the
+/// characters `[0-9a-f]`. Useful for map keys and other places where a canonical form is needed.
+///
+/// # Errors
+///
+/// If `hex` contains a non-hexadecimal character.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use hex_conservative::to_canonical_lower;
+///
+/// assert!(matches!(to_canonical_lower("deadbeef"), Ok(Cow::Borrowed("deadbeef"))));
+/// assert_eq!(to_canonical_lower("DEADbeef").unwrap(), "deadbeef");
+/// assert!(to_canonical_lower("deadbeeg").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_canonical_lower(hex: &str) -> Result<alloc::borrow::Cow<'_, str>, InvalidCharError> {
+    let mut has_upper = false;
+    for (pos, c) in hex.bytes().enumerate() {
+        match iter::hex_digit_with_case(c) {
+            Some((_, Some(Case::Upper))) => has_upper = true,
+            Some(_) => {}
+            None => return Err(InvalidCharError { invalid: c, pos }),
+        }
+    }
+    if has_upper {
+        Ok(alloc::borrow::Cow::Owned(hex.to_ascii_lowercase()))
+    } else {
+        Ok(alloc::borrow::Cow::Borrowed(hex))
+    }
+}
 
 /// Possible case of hex.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -94,7 +921,64 @@ impl Default for Case {
     fn default() -> Self { Case::Lower }
 }
 
+impl core::fmt::Display for Case {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Case::Lower => f.write_str("lower"),
+            Case::Upper => f.write_str("upper"),
+        }
+    }
+}
+
+/// Parses `"lower"`, `"upper"`, `"l"` or `"u"`, ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::Case;
+///
+/// assert_eq!("Upper".parse::<Case>(), Ok(Case::Upper));
+/// assert_eq!("l".parse::<Case>(), Ok(Case::Lower));
+/// assert!("mixed".parse::<Case>().is_err());
+/// ```
+impl core::str::FromStr for Case {
+    type Err = ParseCaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("lower") || s.eq_ignore_ascii_case("l") {
+            Ok(Case::Lower)
+        } else if s.eq_ignore_ascii_case("upper") || s.eq_ignore_ascii_case("u") {
+            Ok(Case::Upper)
+        } else {
+            Err(ParseCaseError)
+        }
+    }
+}
+
 impl Case {
+    /// Returns the case of the hex digit `c`.
+    ///
+    /// Returns `None` for the digits `0-9`, which have no case, and for non-hex characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hex_conservative::Case;
+    ///
+    /// assert_eq!(Case::from_digit('a'), Some(Case::Lower));
+    /// assert_eq!(Case::from_digit('F'), Some(Case::Upper));
+    /// assert_eq!(Case::from_digit('7'), None);
+    /// assert_eq!(Case::from_digit('g'), None);
+    /// ```
+    #[inline]
+    pub const fn from_digit(c: char) -> Option<Case> {
+        match c {
+            'a'..='f' => Some(Case::Lower),
+            'A'..='F' => Some(Case::Upper),
+            _ => None,
+        }
+    }
+
     /// Returns the encoding table.
     ///
     /// The returned table may only contain displayable ASCII chars.
@@ -135,6 +1019,14 @@ mod table {
             [char::from(left), char::from(right)]
         }
 
+        /// Encodes single byte as two ASCII bytes using the given table.
+        ///
+        /// The function guarantees only returning values from the provided table.
+        #[inline]
+        pub(crate) const fn byte_to_ascii(&self, byte: u8) -> [u8; 2] {
+            [self.0[(byte >> 4) as usize], self.0[(byte & 0x0F) as usize]]
+        }
+
         /// Writes the single byte as two ASCII chars in the provided buffer, and returns a `&str`
         /// to that buffer.
         ///
@@ -147,9 +1039,46 @@ mod table {
             let hex_str = unsafe { core::str::from_utf8_unchecked(dest) };
             hex_str
         }
+
+        /// Returns true if `c` is one of the chars in this table.
+        #[inline]
+        pub(crate) fn contains(&self, c: u8) -> bool { self.0.contains(&c) }
     }
 }
 
+/// Decodes a hex string literal at compile time into a `&'static [u8; N]`.
+///
+/// The length of the array is inferred from the literal. Odd length or invalid characters cause
+/// a compile error, this is backed by [`const_decode_to_array`] so no proc-macro is needed.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::hex;
+///
+/// const KEY: &[u8; 4] = hex!("deadbeef");
+/// assert_eq!(KEY, &[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(hex!(""), &[]);
+/// ```
+///
+/// Invalid input fails to compile:
+///
+/// ```compile_fail
+/// let _ = hex_conservative::hex!("deadbeeg");
+/// ```
+///
+/// ```compile_fail
+/// let _ = hex_conservative::hex!("deadbee");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:expr) => {{
+        const HEX: &str = $hex;
+        const BYTES: [u8; HEX.len() / 2] = $crate::const_decode_to_array(HEX);
+        &BYTES
+    }};
+}
+
 /// Quick and dirty macro for parsing hex in tests.
 ///
 /// For improved ergonomics import with: `use hex_conservative::test_hex_unwrap as hex;`
@@ -158,12 +1087,486 @@ macro_rules! test_hex_unwrap (($hex:expr) => (<Vec<u8> as $crate::FromHex>::from
 
 #[cfg(test)]
 mod tests {
-    use crate::test_hex_unwrap as hex;
+    use super::*;
+    use crate::error::InvalidLengthError;
 
     #[test]
     fn parse_hex_into_vector() {
-        let got = hex!("deadbeef");
+        let got = test_hex_unwrap!("deadbeef");
         let want = vec![0xde, 0xad, 0xbe, 0xef];
         assert_eq!(got, want)
     }
+
+    #[test]
+    fn decode_to_slice_reuses_buffer() {
+        let mut buf = [0u8; 4];
+        decode_to_slice("deadbeef", &mut buf).unwrap();
+        assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+        decode_to_slice("01234567", &mut buf).unwrap();
+        assert_eq!(buf, [0x01, 0x23, 0x45, 0x67]);
+
+        let mut empty = [];
+        decode_to_slice("", &mut empty).unwrap();
+    }
+
+    #[test]
+    fn decode_to_slice_error() {
+        let mut buf = [0xaa; 4];
+
+        assert_eq!(decode_to_slice("deadbee", &mut buf), Err(InvalidLengthError::new(7, 8).into()));
+        assert_eq!(
+            decode_to_slice("deadbeefde", &mut buf),
+            Err(InvalidLengthError::new(10, 8).into())
+        );
+        assert_eq!(
+            decode_to_slice("deadbeeg", &mut buf),
+            Err(InvalidCharError { invalid: b'g', pos: 7 }.into())
+        );
+        // The buffer is untouched on error.
+        assert_eq!(buf, [0xaa; 4]);
+    }
+
+    #[test]
+    fn hex_to_arrayvec() {
+        assert_eq!(
+            decode_to_arrayvec::<4>("deadbeef").unwrap().as_slice(),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert_eq!(
+            decode_to_arrayvec::<8>("deadbeef").unwrap().as_slice(),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert!(decode_to_arrayvec::<0>("").unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn hex_to_smallvec() {
+        let v = decode_to_smallvec::<4>("0123").unwrap();
+        assert_eq!(v.as_slice(), [0x01, 0x23]);
+        assert!(!v.spilled());
+
+        let v = decode_to_smallvec::<1>("0123").unwrap();
+        assert_eq!(v.as_slice(), [0x01, 0x23]);
+        assert!(v.spilled());
+
+        assert_eq!(decode_to_smallvec::<4>("012"), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(
+            decode_to_smallvec::<4>("01g3"),
+            Err(InvalidCharError { invalid: b'g', pos: 2 }.into())
+        );
+    }
+
+    #[test]
+    fn hex_to_array_partial() {
+        assert_eq!(decode_to_array_partial::<4>(""), Ok(([0; 4], 0)));
+        assert_eq!(decode_to_array_partial::<4>("0123"), Ok(([0x01, 0x23, 0, 0], 2)));
+        assert_eq!(decode_to_array_partial::<2>("0123"), Ok(([0x01, 0x23], 2)));
+
+        assert_eq!(
+            decode_to_array_partial::<1>("0123"),
+            Err(CapacityError { required: 2, available: 1 }.into())
+        );
+        assert_eq!(
+            decode_to_array_partial::<4>("012"),
+            Err(OddLengthStringError { len: 3 }.into())
+        );
+        assert_eq!(
+            decode_to_array_partial::<4>("01g3"),
+            Err(InvalidCharError { invalid: b'g', pos: 2 }.into())
+        );
+    }
+
+    #[test]
+    fn hex_into_arrayvec() {
+        let mut buf = ArrayVec::<u8, 4>::new();
+        decode_into_arrayvec("", &mut buf).unwrap();
+        decode_into_arrayvec("0123", &mut buf).unwrap();
+        decode_into_arrayvec("45", &mut buf).unwrap();
+        assert_eq!(buf.as_slice(), [0x01, 0x23, 0x45]);
+
+        assert_eq!(
+            decode_into_arrayvec("6789", &mut buf),
+            Err(CapacityError { required: 2, available: 1 }.into())
+        );
+        assert_eq!(
+            decode_into_arrayvec("6", &mut buf),
+            Err(OddLengthStringError { len: 1 }.into())
+        );
+        let mut buf = ArrayVec::<u8, 4>::new();
+        buf.push(0xff);
+        assert_eq!(
+            decode_into_arrayvec("01g3", &mut buf),
+            Err(InvalidCharError { invalid: b'g', pos: 2 }.into())
+        );
+        // Bytes decoded before the error are removed again.
+        assert_eq!(buf.as_slice(), [0xff]);
+    }
+
+    #[test]
+    fn hex_to_arrayvec_error() {
+        assert_eq!(
+            decode_to_arrayvec::<3>("deadbeef"),
+            Err(CapacityError { required: 4, available: 3 }.into())
+        );
+        assert_eq!(decode_to_arrayvec::<4>("deadbee"), Err(OddLengthStringError { len: 7 }.into()));
+        assert_eq!(
+            decode_to_arrayvec::<4>("deadbeeg"),
+            Err(InvalidCharError { invalid: b'g', pos: 7 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_hex_owned_str() {
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let want = [0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(from_hex_str::<[u8; 4]>(String::from("deadbeef")), Ok(want));
+        assert_eq!(from_hex_str::<[u8; 4]>("deadbeef"), Ok(want));
+        assert_eq!(from_hex_str::<Vec<u8>>(&String::from("deadbeef")).unwrap(), want);
+        assert!(from_hex_str::<[u8; 4]>(String::from("deadbe")).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_many_slices() {
+        let items = [vec![0xde, 0xad], vec![], vec![0xbe, 0xef]];
+        let got = encode_many(items.iter().map(Vec::as_slice), Case::Upper);
+        assert_eq!(got, ["DEAD", "", "BEEF"]);
+        assert!(encode_many(core::iter::empty(), Case::Lower).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_from_byte_iter() {
+        assert_eq!(hex_from_bytes(core::iter::empty(), Case::Lower), "");
+        assert_eq!(hex_from_bytes([0xde, 0xad, 0xbe, 0xef], Case::Lower), "deadbeef");
+
+        // Size hint lower bound is zero, the string grows as needed.
+        let filtered = (0u8..=0xff).filter(|b| b % 0x40 == 0x0f);
+        assert_eq!(hex_from_bytes(filtered, Case::Upper), "0F4F8FCF");
+    }
+
+    #[test]
+    fn encode_single_byte() {
+        for byte in 0..=255u8 {
+            for case in [Case::Lower, Case::Upper] {
+                let want = case.table().byte_to_chars(byte).map(|c| c as u8);
+                assert_eq!(encode_byte(byte, case), want);
+                assert_eq!(decode_byte(want[0], want[1]), Ok(byte));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_single_byte() {
+        for byte in 0..=255u8 {
+            let [hi, lo] = Case::Upper.table().byte_to_chars(byte).map(|c| c as u8);
+            assert_eq!(decode_byte(hi, lo), Ok(byte));
+            assert_eq!(decode_byte(hi.to_ascii_lowercase(), lo), Ok(byte));
+        }
+        assert_eq!(decode_byte(b'g', b'0'), Err(InvalidCharError { invalid: b'g', pos: 0 }));
+        assert_eq!(decode_byte(b'0', b'g'), Err(InvalidCharError { invalid: b'g', pos: 1 }));
+        assert_eq!(decode_byte(b'x', b'y'), Err(InvalidCharError { invalid: b'x', pos: 0 }));
+    }
+
+    #[test]
+    fn hex_to_array_str() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(encode_to_array_str::<8>(&bytes, Case::Lower).unwrap().as_str(), "deadbeef");
+        assert_eq!(encode_to_array_str::<9>(&bytes, Case::Upper).unwrap().as_str(), "DEADBEEF");
+        assert_eq!(encode_to_array_str::<0>(&[], Case::Lower).unwrap().as_str(), "");
+        assert_eq!(
+            encode_to_array_str::<7>(&bytes, Case::Lower),
+            Err(CapacityError { required: 4, available: 3 })
+        );
+    }
+
+    #[test]
+    fn hex_macro() {
+        const BYTES: &[u8; 4] = hex!("deadBEEF");
+        assert_eq!(BYTES, &[0xde, 0xad, 0xbe, 0xef]);
+        let empty: &[u8; 0] = hex!("");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn hex_in_place() {
+        let mut buf = *b"deadBEEF";
+        assert_eq!(decode_in_place(&mut buf).unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(&buf[4..], b"BEEF");
+
+        let mut buf = [0u8; 0];
+        assert!(decode_in_place(&mut buf).unwrap().is_empty());
+    }
+
+    #[test]
+    fn hex_in_place_error() {
+        let mut buf = *b"deadbee";
+        assert_eq!(decode_in_place(&mut buf), Err(OddLengthStringError { len: 7 }.into()));
+
+        let mut buf = *b"deadbeeg";
+        assert_eq!(
+            decode_in_place(&mut buf),
+            Err(InvalidCharError { invalid: b'g', pos: 7 }.into())
+        );
+        assert_eq!(&buf, b"deadbeeg");
+    }
+
+    #[test]
+    fn ct_hex_digit_matches_to_digit() {
+        for c in 0..=255u8 {
+            let want = (c as char).to_digit(16).map_or(0xffff, |d| d as u16);
+            assert_eq!(ct_hex_digit(c), want, "char {:?}", c as char);
+        }
+    }
+
+    #[test]
+    fn hex_to_array_ct() {
+        assert_eq!(decode_to_array_ct::<0>(""), Ok([]));
+        assert_eq!(decode_to_array_ct::<4>("deadBEEF"), Ok([0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(
+            decode_to_array_ct::<8>("0123456789abcdef"),
+            Ok(const_decode_to_array("0123456789abcdef"))
+        );
+    }
+
+    #[test]
+    fn hex_to_array_ct_error() {
+        assert_eq!(decode_to_array_ct::<4>("deadbeeg"), Err(HexToArrayCtError::InvalidChar));
+        assert_eq!(decode_to_array_ct::<4>("gdeadbee"), Err(HexToArrayCtError::InvalidChar));
+        assert_eq!(decode_to_array_ct::<2>("de«"), Err(HexToArrayCtError::InvalidChar));
+        assert_eq!(decode_to_array_ct::<2>("de /"), Err(HexToArrayCtError::InvalidChar));
+        assert_eq!(decode_to_array_ct::<4>("deadbee"), Err(InvalidLengthError::new(7, 8).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hex_to_writer() {
+        let mut out = Vec::new();
+        assert_eq!(decode_to_writer("", &mut out).unwrap(), 0);
+        assert!(out.is_empty());
+
+        let hex = "deadbeef".repeat(300);
+        assert_eq!(decode_to_writer(&hex, &mut out).unwrap(), 1200);
+        assert_eq!(out, [0xde, 0xad, 0xbe, 0xef].repeat(300));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hex_to_writer_error() {
+        let mut out = Vec::new();
+        assert!(matches!(
+            decode_to_writer("abc", &mut out),
+            Err(DecodeToWriterError::OddLengthString(e)) if e == OddLengthStringError { len: 3 }
+        ));
+        assert!(out.is_empty());
+
+        // The first chunk is written, the invalid char is in the second.
+        let mut hex = "ab".repeat(600);
+        hex.replace_range(1101..1102, "g");
+        assert!(matches!(
+            decode_to_writer(&hex, &mut out),
+            Err(DecodeToWriterError::InvalidChar(e)) if e == InvalidCharError { invalid: b'g', pos: 1101 }
+        ));
+        assert_eq!(out.len(), 512);
+
+        let mut full = [0u8; 1];
+        let mut writer = &mut full[..];
+        assert!(matches!(decode_to_writer("abcd", &mut writer), Err(DecodeToWriterError::Io(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn encode_lower_upper() {
+        assert_eq!(encode_lower(&[]), "");
+        assert_eq!(encode_upper(&[]), "");
+        assert_eq!(encode_lower(&[0xde, 0xad, 0xbe, 0xef, 0x01]), "deadbeef01");
+        assert_eq!(encode_upper(&[0xde, 0xad, 0xbe, 0xef, 0x01]), "DEADBEEF01");
+    }
+
+    #[test]
+    fn decoded_len_of_hex() {
+        assert_eq!(decoded_len(""), Ok(0));
+        assert_eq!(decoded_len("deadbeef"), Ok(4));
+        assert_eq!(decoded_len("zz"), Ok(1));
+        assert_eq!(decoded_len("abc"), Err(OddLengthStringError { len: 3 }));
+    }
+
+    #[test]
+    fn validate_hex() {
+        assert_eq!(validate(""), Ok(()));
+        assert_eq!(validate("deadbeef"), Ok(()));
+        assert_eq!(validate("DeAdBeEf"), Ok(()));
+
+        assert_eq!(validate("abc"), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(validate("abcg"), Err(InvalidCharError { invalid: b'g', pos: 3 }.into()));
+        assert_eq!(validate("0xab"), Err(InvalidCharError { invalid: b'x', pos: 1 }.into()));
+        // Odd length is reported before any invalid character.
+        assert_eq!(validate("gab"), Err(OddLengthStringError { len: 3 }.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_hex_matches_vec_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // Cover empty input and inputs spanning several chunks of the internal buffer.
+        for len in [0, 1, 63, 64, 65, 200] {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut hasher = DefaultHasher::new();
+            hash_hex(&bytes.to_upper_hex_string(), &mut hasher).unwrap();
+            assert_eq!(hasher.finish(), hash(&bytes), "length {}", len);
+            assert_eq!(hasher.finish(), hash(bytes.as_slice()));
+        }
+    }
+
+    #[test]
+    fn hash_hex_error() {
+        struct Sink;
+
+        impl core::hash::Hasher for Sink {
+            fn finish(&self) -> u64 { 0 }
+            fn write(&mut self, _: &[u8]) {}
+        }
+
+        assert_eq!(hash_hex("abc", &mut Sink), Err(OddLengthStringError { len: 3 }.into()));
+        assert_eq!(
+            hash_hex("abcg", &mut Sink),
+            Err(InvalidCharError { invalid: b'g', pos: 3 }.into())
+        );
+    }
+
+    #[test]
+    fn eq_hex_compares_decoded() {
+        assert!(eq_hex(&[], ""));
+        assert!(eq_hex(&[0xde, 0xad, 0xbe, 0xef], "deadbeef"));
+        assert!(eq_hex(&[0xde, 0xad, 0xbe, 0xef], "DEADBEEF"));
+        assert!(eq_hex(&[0xde, 0xad, 0xbe, 0xef], "DeAdBeEf"));
+
+        assert!(!eq_hex(&[0xde, 0xad, 0xbe, 0xef], "deadbeee"));
+        assert!(!eq_hex(&[0xde, 0xad, 0xbe, 0xef], "deadbee"));
+        assert!(!eq_hex(&[0xde, 0xad, 0xbe, 0xef], "deadbeef00"));
+        assert!(!eq_hex(&[0xde, 0xad, 0xbe, 0xef], "0xdeadbeef"));
+        assert!(!eq_hex(&[0xde], "dg"));
+        assert!(!eq_hex(&[0xde], "«"));
+    }
+
+    #[test]
+    fn case_display_and_parse() {
+        use core::str::FromStr;
+
+        for case in [Case::Lower, Case::Upper] {
+            let mut buf = arrayvec::ArrayString::<5>::new();
+            core::fmt::write(&mut buf, format_args!("{}", case)).unwrap();
+            assert_eq!(Case::from_str(&buf), Ok(case));
+        }
+        assert_eq!(Case::from_str("LOWER"), Ok(Case::Lower));
+        assert_eq!(Case::from_str("U"), Ok(Case::Upper));
+        assert_eq!(Case::from_str(""), Err(ParseCaseError));
+        assert_eq!(Case::from_str("lowercase"), Err(ParseCaseError));
+    }
+
+    #[test]
+    fn case_from_digit() {
+        for c in "abcdef".chars() {
+            assert_eq!(Case::from_digit(c), Some(Case::Lower));
+        }
+        for c in "ABCDEF".chars() {
+            assert_eq!(Case::from_digit(c), Some(Case::Upper));
+        }
+        for c in "0123456789gGzZ x«".chars() {
+            assert_eq!(Case::from_digit(c), None);
+        }
+    }
+
+    #[test]
+    fn detect_case_single_case() {
+        assert_eq!(detect_case(""), Ok(None));
+        assert_eq!(detect_case("0123456789"), Ok(None));
+        assert_eq!(detect_case("0123456789abcdef"), Ok(Some(Case::Lower)));
+        assert_eq!(detect_case("0123456789ABCDEF"), Ok(Some(Case::Upper)));
+    }
+
+    #[test]
+    fn detect_case_error() {
+        assert_eq!(detect_case("00aB"), Err(MixedCaseError { invalid: b'B', pos: 3 }.into()));
+        assert_eq!(detect_case("A0b0"), Err(MixedCaseError { invalid: b'b', pos: 2 }.into()));
+        assert_eq!(detect_case("01g3"), Err(InvalidCharError { invalid: b'g', pos: 2 }.into()));
+        assert_eq!(detect_case("aBg"), Err(MixedCaseError { invalid: b'B', pos: 1 }.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn canonical_lower() {
+        use alloc::borrow::Cow;
+
+        assert!(matches!(to_canonical_lower(""), Ok(Cow::Borrowed(""))));
+        assert!(matches!(to_canonical_lower("0123abcdef"), Ok(Cow::Borrowed("0123abcdef"))));
+
+        let owned = to_canonical_lower("0123ABCdef").unwrap();
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(owned, "0123abcdef");
+
+        assert_eq!(to_canonical_lower("ABCx"), Err(InvalidCharError { invalid: b'x', pos: 3 }));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode_into_reused_vec() {
+        let mut buf = vec![0x01, 0x02, 0x03];
+        assert_eq!(decode_into_vec("dead", &mut buf), Ok(()));
+        assert_eq!(buf, [0xde, 0xad]);
+        assert_eq!(decode_into_vec("", &mut buf), Ok(()));
+        assert!(buf.is_empty());
+
+        buf.push(0x01);
+        assert_eq!(decode_into_vec("dea", &mut buf), Err(OddLengthStringError { len: 3 }.into()));
+        assert!(buf.is_empty());
+        assert_eq!(
+            decode_into_vec("deadbgef", &mut buf),
+            Err(InvalidCharError { invalid: b'g', pos: 5 }.into())
+        );
+        assert_eq!(buf, [0xde, 0xad]);
+    }
+
+    #[test]
+    fn decode_to_array_reversed() {
+        assert_eq!(decode_to_array_rev::<4>("deadbeef"), Ok([0xef, 0xbe, 0xad, 0xde]));
+        assert_eq!(decode_to_array_rev::<0>(""), Ok([]));
+        assert_eq!(decode_to_array_rev::<4>("deadbe"), Err(InvalidLengthError::new(6, 8).into()));
+        assert_eq!(
+            decode_to_array_rev::<4>("dexdbeef"),
+            Err(InvalidCharError { invalid: b'x', pos: 2 }.into())
+        );
+    }
+
+    #[test]
+    fn const_decode() {
+        const EMPTY: [u8; 0] = const_decode_to_array("");
+        const LOWER: [u8; 4] = const_decode_to_array("deadbeef");
+        const UPPER: [u8; 8] = const_decode_to_array("0123456789ABCDEF");
+
+        assert_eq!(EMPTY, [0u8; 0]);
+        assert_eq!(LOWER, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(UPPER, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+        assert_eq!(const_decode_to_array::<2>("dEaD"), [0xde, 0xad]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_decode_invalid_length() { let _ = const_decode_to_array::<2>("dead00"); }
+
+    #[test]
+    #[should_panic]
+    fn const_decode_invalid_char() { let _ = const_decode_to_array::<2>("deag"); }
 }
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Implements configurable hex decoding.
+
+use crate::alloc::vec::Vec;
+use crate::decode::NibblePad;
+use crate::error::{DecodeOptionsError, InvalidCharError, MixedCaseError, OddDigitCountError};
+use crate::iter::hex_digit_with_case;
+use crate::Case;
+
+/// Configures how hex is decoded.
+///
+/// By default this accepts the same input as `Vec::from_hex`, each option relaxes or tightens
+/// what is accepted. The functions in the [`decode`](crate::decode) module are shorthands for the
+/// common cases.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::DecodeOptions;
+///
+/// let options = DecodeOptions::new().allow_prefix(true).skip_whitespace(true);
+/// assert_eq!(options.decode_to_vec("0xdead beef").expect("valid hex"), [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let options = options.require_single_case(true);
+/// assert!(options.decode_to_vec("0xDEAD beef").is_err());
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DecodeOptions {
+    allow_prefix: bool,
+    skip_whitespace: bool,
+    require_single_case: bool,
+    pad_odd_length: Option<NibblePad>,
+}
+
+impl DecodeOptions {
+    /// Creates options accepting the same input as `Vec::from_hex`.
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Sets whether a single leading `0x` or `0X` is skipped.
+    #[inline]
+    pub fn allow_prefix(mut self, yes: bool) -> Self {
+        self.allow_prefix = yes;
+        self
+    }
+
+    /// Sets whether ASCII spaces, tabs, newlines and carriage returns are skipped.
+    ///
+    /// Whitespace is not counted when checking the number of digits.
+    #[inline]
+    pub fn skip_whitespace(mut self, yes: bool) -> Self {
+        self.skip_whitespace = yes;
+        self
+    }
+
+    /// Sets whether input mixing lower and upper case digits is rejected.
+    #[inline]
+    pub fn require_single_case(mut self, yes: bool) -> Self {
+        self.require_single_case = yes;
+        self
+    }
+
+    /// Sets where a zero digit is added to an odd number of hex digits instead of rejecting it.
+    ///
+    /// With `None`, the default, an odd number of digits is an error. See [`NibblePad`] for the
+    /// effect of each position.
+    #[inline]
+    pub fn pad_odd_length(mut self, pad: Option<NibblePad>) -> Self {
+        self.pad_odd_length = pad;
+        self
+    }
+
+    /// Decodes `hex` into a vector according to these options.
+    ///
+    /// # Errors
+    ///
+    /// - If `hex` contains a non-hexadecimal character that isn't skipped. Positions refer to the
+    ///   input string, including any prefix and whitespace.
+    /// - If single case is required and `hex` contains mixed case digits, the first digit whose
+    ///   case differs from the case of the preceding alphabetic digits is reported.
+    /// - If `hex` contains an odd number of hex digits and padding is not enabled. Characters are
+    ///   checked first.
+    #[inline]
+    pub fn decode_to_vec(&self, hex: &str) -> Result<Vec<u8>, DecodeOptionsError> {
+        self.decode_to_vec_with_case(hex).map(|(ret, _)| ret)
+    }
+
+    /// Same as [`Self::decode_to_vec`] but also returns the case of the first alphabetic digit.
+    ///
+    /// If single case is required this is the case of all alphabetic digits.
+    pub(crate) fn decode_to_vec_with_case(
+        &self,
+        hex: &str,
+    ) -> Result<(Vec<u8>, Option<Case>), DecodeOptionsError> {
+        let offset = match hex.get(..2) {
+            Some("0x") | Some("0X") if self.allow_prefix => 2,
+            _ => 0,
+        };
+        let mut ret = Vec::with_capacity((hex.len() - offset + 1) / 2);
+        // The high nibble of a byte whose low nibble hasn't been decoded yet and its position.
+        let mut high = None;
+        let mut case = None;
+        for (pos, c) in hex.bytes().enumerate().skip(offset) {
+            if self.skip_whitespace && matches!(c, b' ' | b'\t' | b'\n' | b'\r') {
+                continue;
+            }
+            let (nibble, this) =
+                hex_digit_with_case(c).ok_or(InvalidCharError { invalid: c, pos })?;
+            match (case, this) {
+                (None, _) => case = this,
+                (Some(case), Some(this)) if self.require_single_case && case != this =>
+                    return Err(MixedCaseError { invalid: c, pos }.into()),
+                _ => {}
+            }
+            match high.take() {
+                Some((high, _)) => ret.push((high << 4) | nibble),
+                None => high = Some((nibble, pos)),
+            }
+        }
+        if let Some((high, pos)) = high {
+            match self.pad_odd_length {
+                None => return Err(OddDigitCountError { digits: ret.len() * 2 + 1, pos }.into()),
+                Some(NibblePad::Trailing) => ret.push(high << 4),
+                Some(NibblePad::Leading) => {
+                    // Shift all digits by one nibble towards the end.
+                    let mut carry = 0;
+                    for byte in ret.iter_mut() {
+                        let low = *byte & 0x0f;
+                        *byte = (carry << 4) | (*byte >> 4);
+                        carry = low;
+                    }
+                    ret.push((carry << 4) | high);
+                }
+            }
+        }
+        Ok((ret, case))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options() {
+        let options = DecodeOptions::new();
+        assert_eq!(options.decode_to_vec("deADbeef").unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(
+            options.decode_to_vec("0xdead"),
+            Err(InvalidCharError { invalid: b'x', pos: 1 }.into())
+        );
+        assert_eq!(
+            options.decode_to_vec("de ad"),
+            Err(InvalidCharError { invalid: b' ', pos: 2 }.into())
+        );
+        assert_eq!(
+            options.decode_to_vec("dea"),
+            Err(OddDigitCountError { digits: 3, pos: 2 }.into())
+        );
+    }
+
+    #[test]
+    fn allow_prefix() {
+        let options = DecodeOptions::new().allow_prefix(true);
+        assert_eq!(options.decode_to_vec("0xdead").unwrap(), [0xde, 0xad]);
+        assert_eq!(options.decode_to_vec("0Xdead").unwrap(), [0xde, 0xad]);
+        assert_eq!(options.decode_to_vec("dead").unwrap(), [0xde, 0xad]);
+        assert!(options.decode_to_vec("0x").unwrap().is_empty());
+        assert_eq!(
+            options.decode_to_vec("0x0xdead"),
+            Err(InvalidCharError { invalid: b'x', pos: 3 }.into())
+        );
+    }
+
+    #[test]
+    fn skip_whitespace() {
+        let options = DecodeOptions::new().skip_whitespace(true);
+        assert_eq!(options.decode_to_vec(" de\tad\r\n").unwrap(), [0xde, 0xad]);
+        assert_eq!(
+            options.decode_to_vec("d e a "),
+            Err(OddDigitCountError { digits: 3, pos: 4 }.into())
+        );
+        assert_eq!(
+            options.decode_to_vec("de ag"),
+            Err(InvalidCharError { invalid: b'g', pos: 4 }.into())
+        );
+    }
+
+    #[test]
+    fn require_single_case() {
+        let options = DecodeOptions::new().require_single_case(true);
+        assert_eq!(options.decode_to_vec("DEAD0123").unwrap(), [0xde, 0xad, 0x01, 0x23]);
+        assert_eq!(
+            options.decode_to_vec("00aB"),
+            Err(MixedCaseError { invalid: b'B', pos: 3 }.into())
+        );
+    }
+
+    #[test]
+    fn pad_odd_length() {
+        let options = DecodeOptions::new().pad_odd_length(Some(NibblePad::Leading));
+        assert_eq!(options.decode_to_vec("abcde").unwrap(), [0x0a, 0xbc, 0xde]);
+        assert_eq!(options.decode_to_vec("abcd").unwrap(), [0xab, 0xcd]);
+        assert_eq!(options.decode_to_vec("a").unwrap(), [0x0a]);
+        let options = DecodeOptions::new().pad_odd_length(Some(NibblePad::Trailing));
+        assert_eq!(options.decode_to_vec("abcde").unwrap(), [0xab, 0xcd, 0xe0]);
+        assert_eq!(
+            options.decode_to_vec("abg"),
+            Err(InvalidCharError { invalid: b'g', pos: 2 }.into())
+        );
+
+        // Whitespace isn't counted and the prefix isn't padded.
+        let options = options.allow_prefix(true).skip_whitespace(true);
+        assert_eq!(options.decode_to_vec("0xab c").unwrap(), [0xab, 0xc0]);
+        let options = options.pad_odd_length(Some(NibblePad::Leading));
+        assert_eq!(options.decode_to_vec("0xab c").unwrap(), [0x0a, 0xbc]);
+    }
+
+    #[test]
+    fn combined() {
+        let options =
+            DecodeOptions::new().allow_prefix(true).skip_whitespace(true).require_single_case(true);
+        assert_eq!(options.decode_to_vec("0xAB CD").unwrap(), [0xab, 0xcd]);
+        assert_eq!(
+            options.decode_to_vec("0xAB cd"),
+            Err(MixedCaseError { invalid: b'c', pos: 5 }.into())
+        );
+        assert_eq!(
+            options.decode_to_vec("0xabc"),
+            Err(OddDigitCountError { digits: 3, pos: 4 }.into())
+        );
+    }
+}
@@ -5,7 +5,7 @@
 use core::{fmt, str};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use crate::alloc::vec::Vec;
+use crate::alloc::{boxed::Box, vec::Vec};
 use crate::error::InvalidLengthError;
 use crate::iter::HexToBytesIter;
 
@@ -13,12 +13,36 @@ use crate::iter::HexToBytesIter;
 pub use crate::error::{HexToBytesError, HexToArrayError};
 
 /// Trait for objects that can be deserialized from hex strings.
+///
+/// Note that we cannot provide `TryFrom<&str>` for `[u8; N]` or `Vec<u8>` because of the orphan
+/// rules (neither the trait nor the types are local to this crate). Generic code should use a
+/// `FromHex` bound instead.
+///
+/// # Examples
+///
+/// ```
+/// use hex_conservative::FromHex;
+///
+/// fn parse<T: FromHex>(s: &str) -> Result<T, T::Error> { T::from_hex(s) }
+///
+/// let a: [u8; 4] = parse("deadbeef").expect("valid hex");
+/// assert_eq!(a, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
 pub trait FromHex: Sized {
     /// Error type returned while parsing hex string.
     type Error: Sized + fmt::Debug + fmt::Display;
 
     /// Produces an object from a hex string.
     fn from_hex(s: &str) -> Result<Self, Self::Error>;
+
+    /// Produces an object from a hex string with an optional `0x` or `0X` prefix.
+    ///
+    /// The default implementation strips a single leading prefix and calls [`FromHex::from_hex`].
+    /// The implementations in this crate report error positions and lengths relative to `s`
+    /// including the prefix.
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        Self::from_hex(crate::strip_prefix(s))
+    }
 }
 
 #[cfg(any(test, feature = "std", feature = "alloc"))]
@@ -26,10 +50,61 @@ impl FromHex for Vec<u8> {
     type Error = HexToBytesError;
 
     fn from_hex(s: &str) -> Result<Self, Self::Error> {
-        Ok(HexToBytesIter::new(s)?.drain_to_vec()?)
+        Ok(HexToBytesIter::new(s)?.collect_to_vec()?)
+    }
+
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        crate::decode_prefixed(s, Self::from_hex)
     }
 }
 
+#[cfg(any(test, feature = "std", feature = "alloc"))]
+impl FromHex for Box<[u8]> {
+    type Error = HexToBytesError;
+
+    fn from_hex(s: &str) -> Result<Self, Self::Error> { Ok(Vec::from_hex(s)?.into_boxed_slice()) }
+
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        Ok(Vec::from_hex_prefixed(s)?.into_boxed_slice())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl FromHex for bytes::BytesMut {
+    type Error = HexToBytesError;
+
+    fn from_hex(s: &str) -> Result<Self, Self::Error> {
+        use bytes::BufMut;
+
+        let iter = HexToBytesIter::new(s)?;
+        let mut ret = bytes::BytesMut::with_capacity(iter.len());
+        for byte in iter {
+            ret.put_u8(byte?);
+        }
+        Ok(ret)
+    }
+
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        crate::decode_prefixed(s, Self::from_hex)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl FromHex for bytes::Bytes {
+    type Error = HexToBytesError;
+
+    fn from_hex(s: &str) -> Result<Self, Self::Error> { Ok(bytes::BytesMut::from_hex(s)?.freeze()) }
+
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        Ok(bytes::BytesMut::from_hex_prefixed(s)?.freeze())
+    }
+}
+
+/// Parses exactly `2 * LEN` hex digits into an array.
+///
+/// The length is checked before any character is decoded, an input of the wrong length always
+/// returns [`HexToArrayError::InvalidLength`] even if it also contains invalid characters. Wrapper
+/// types delegating to this impl therefore report length errors without doing partial work.
 impl<const LEN: usize> FromHex for [u8; LEN] {
     type Error = HexToArrayError;
 
@@ -40,10 +115,179 @@ impl<const LEN: usize> FromHex for [u8; LEN] {
             HexToBytesIter::new_unchecked(s).drain_to_slice(&mut ret)?;
             Ok(ret)
         } else {
-            Err(InvalidLengthError { invalid: s.len(), expected: 2 * LEN }.into())
+            Err(InvalidLengthError::new(s.len(), 2 * LEN).into())
+        }
+    }
+
+    fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+        crate::decode_prefixed(s, Self::from_hex)
+    }
+}
+
+/// Implements `FromHex` for tuples of byte arrays.
+///
+/// The hex string is the concatenation of the encoded fields and must have exactly two digits per
+/// byte of all the arrays combined. Positions of invalid characters are relative to the whole
+/// input.
+macro_rules! impl_from_hex_for_array_tuple {
+    ($($len:ident => $field:ident),*) => {
+        impl<$(const $len: usize),*> FromHex for ($([u8; $len],)*) {
+            type Error = HexToArrayError;
+
+            fn from_hex(s: &str) -> Result<Self, Self::Error> {
+                let expected = 2 * (0 $(+ $len)*);
+                if s.len() != expected {
+                    return Err(InvalidLengthError::new(s.len(), expected).into());
+                }
+                $(let mut $field = [0u8; $len];)*
+                // checked above
+                let mut iter = HexToBytesIter::new_unchecked(s);
+                $(
+                    for dst in $field.iter_mut() {
+                        *dst = iter.next().expect("length checked above")?;
+                    }
+                )*
+                Ok(($($field,)*))
+            }
+
+            fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> {
+                crate::decode_prefixed(s, Self::from_hex)
+            }
         }
     }
 }
+impl_from_hex_for_array_tuple!(A => a, B => b);
+impl_from_hex_for_array_tuple!(A => a, B => b, C => c);
+
+/// Implements `FromHex` for unsigned integer types.
+///
+/// The hex string is big-endian, may have a `0x` or `0X` prefix, and must have exactly two digits
+/// per byte of the integer type. Error positions and lengths refer to `s` including the prefix.
+macro_rules! impl_from_hex_for_uint {
+    ($($ty:ident),*) => {
+        $(
+            impl FromHex for $ty {
+                type Error = HexToArrayError;
+
+                fn from_hex(s: &str) -> Result<Self, Self::Error> {
+                    let bytes = <[u8; core::mem::size_of::<$ty>()]>::from_hex_prefixed(s)?;
+                    Ok($ty::from_be_bytes(bytes))
+                }
+
+                // `from_hex` already accepts a prefix.
+                fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> { Self::from_hex(s) }
+            }
+        )*
+    }
+}
+impl_from_hex_for_uint!(u8, u16, u32, u64, u128);
+
+/// Implements `FromHex` for signed integer types.
+///
+/// Parses as the unsigned type of the same width, the two's complement bit pattern is used as-is
+/// e.g., `i8::from_hex("ff")` is `-1`.
+macro_rules! impl_from_hex_for_int {
+    ($($ty:ident => $uty:ident),*) => {
+        $(
+            impl FromHex for $ty {
+                type Error = HexToArrayError;
+
+                fn from_hex(s: &str) -> Result<Self, Self::Error> {
+                    Ok($uty::from_hex(s)? as $ty)
+                }
+
+                // `from_hex` already accepts a prefix.
+                fn from_hex_prefixed(s: &str) -> Result<Self, Self::Error> { Self::from_hex(s) }
+            }
+        )*
+    }
+}
+impl_from_hex_for_int!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
+
+/// Adds `core::str::FromStr` for a type wrapping a byte array.
+///
+/// This is the parsing companion of [`impl_fmt_traits`](crate::impl_fmt_traits), it accepts the
+/// same input. The type must implement `From<[u8; LENGTH]>`, the hex string is parsed using
+/// `<[u8; LENGTH] as FromHex>::from_hex` and errors are reported as [`HexToArrayError`].
+///
+/// # Examples
+///
+/// ```
+/// # use core::borrow::Borrow;
+/// # use hex_conservative::{impl_fmt_traits, impl_parse_traits};
+/// struct Wrapper([u8; 4]);
+///
+/// impl Borrow<[u8]> for Wrapper {
+///     fn borrow(&self) -> &[u8] { &self.0[..] }
+/// }
+///
+/// impl From<[u8; 4]> for Wrapper {
+///     fn from(bytes: [u8; 4]) -> Self { Wrapper(bytes) }
+/// }
+///
+/// impl_fmt_traits! {
+///     #[display_backward(true)]
+///     impl fmt_traits for Wrapper {
+///         const LENGTH: usize = 4;
+///     }
+/// }
+///
+/// impl_parse_traits! {
+///     #[display_backward(true)]
+///     impl parse_traits for Wrapper {
+///         const LENGTH: usize = 4;
+///     }
+/// }
+///
+/// let w = "78563412".parse::<Wrapper>().expect("valid hex");
+/// assert_eq!(w.0, [0x12, 0x34, 0x56, 0x78]);
+/// assert_eq!(format!("{}", w), "78563412");
+/// ```
+#[macro_export]
+macro_rules! impl_parse_traits {
+    // Without generic and trait bounds and without display_backward attribute.
+    (impl parse_traits for $ty:ident { const LENGTH: usize = $len:expr; }) => {
+        $crate::impl_parse_traits! {
+            #[display_backward(false)]
+            impl<> parse_traits for $ty<> {
+                const LENGTH: usize = $len;
+            }
+        }
+    };
+    // Without generic and trait bounds and with display_backward attribute.
+    (#[display_backward($reverse:expr)] impl parse_traits for $ty:ident { const LENGTH: usize = $len:expr; }) => {
+        $crate::impl_parse_traits! {
+            #[display_backward($reverse)]
+            impl<> parse_traits for $ty<> {
+                const LENGTH: usize = $len;
+            }
+        }
+    };
+    // With generic and trait bounds and without display_backward attribute.
+    (impl<$($gen:ident: $gent:ident),*> parse_traits for $ty:ident<$($unused:ident),*> { const LENGTH: usize = $len:expr; }) => {
+        $crate::impl_parse_traits! {
+            #[display_backward(false)]
+            impl<$($gen: $gent),*> parse_traits for $ty<$($unused),*> {
+                const LENGTH: usize = $len;
+            }
+        }
+    };
+    // With generic and trait bounds and display_backward attribute.
+    (#[display_backward($reverse:expr)] impl<$($gen:ident: $gent:ident),*> parse_traits for $ty:ident<$($unused:ident),*> { const LENGTH: usize = $len:expr; }) => {
+        impl<$($gen: $gent),*> $crate::_export::_core::str::FromStr for $ty<$($gen),*> {
+            type Err = $crate::HexToArrayError;
+
+            #[inline]
+            fn from_str(s: &str) -> $crate::_export::_core::result::Result<Self, Self::Err> {
+                let mut bytes = <[u8; $len] as $crate::FromHex>::from_hex(s)?;
+                if $reverse {
+                    bytes.reverse();
+                }
+                $crate::_export::_core::result::Result::Ok($crate::_export::_core::convert::From::from(bytes))
+            }
+        }
+    };
+}
 
 #[cfg(test)]
 mod tests {
@@ -61,10 +305,7 @@ mod tests {
         let badchar3 = "«23456789abcdef";
 
         assert_eq!(Vec::<u8>::from_hex(oddlen), Err(OddLengthStringError { len: 17 }.into()));
-        assert_eq!(
-            <[u8; 4]>::from_hex(oddlen),
-            Err(InvalidLengthError { invalid: 17, expected: 8 }.into())
-        );
+        assert_eq!(<[u8; 4]>::from_hex(oddlen), Err(InvalidLengthError::new(17, 8).into()));
         assert_eq!(
             Vec::<u8>::from_hex(badchar1),
             Err(InvalidCharError { pos: 0, invalid: b'Z' }.into())
@@ -79,6 +320,71 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_error_full_char() {
+        use crate::alloc::string::ToString;
+        use crate::error::InvalidCharError;
+
+        let input = "00«0000";
+        let err = InvalidCharError { pos: 2, invalid: 194 };
+        assert_eq!(<[u8; 4]>::from_hex(input), Err(err.clone().into()));
+        assert_eq!(err.invalid_char_in(input), Some(('«', 2)));
+        assert_eq!(err.to_string(), "invalid hex char byte 0xc2 at pos 2");
+
+        // The continuation byte is reported when decoding backwards.
+        let input = "0«000";
+        let err = HexToBytesIter::new(input).unwrap().nth_back(1).unwrap().unwrap_err();
+        assert_eq!(err, InvalidCharError { pos: 2, invalid: 171 });
+        assert_eq!(err.invalid_char_in(input), Some(('«', 1)));
+
+        let err = InvalidCharError { pos: 4, invalid: b'g' };
+        assert_eq!(err.invalid_char_in("««g0"), Some(('g', 2)));
+        assert_eq!(err.invalid_char_in("0000"), None);
+        assert_eq!(err.invalid_char_in(""), None);
+        assert_eq!(err.to_string(), "invalid hex char 'g' at pos 4");
+    }
+
+    #[test]
+    fn invalid_char_lossy() {
+        let err = <[u8; 2]>::from_hex("0z00").unwrap_err();
+        let err = match err {
+            HexToArrayError::InvalidChar(e) => e,
+            _ => panic!("expected invalid char error"),
+        };
+        assert_eq!(err.invalid_char_lossy(), 'z');
+        assert_eq!(crate::error::InvalidCharError::new(b'\n', 0).invalid_char_lossy(), '\n');
+        assert_eq!(
+            crate::error::InvalidCharError::new(0xc2, 0).invalid_char_lossy(),
+            char::REPLACEMENT_CHARACTER
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn invalid_char_context() {
+        use crate::alloc::string::ToString;
+        use crate::error::InvalidCharError;
+
+        let err = InvalidCharError { pos: 4, invalid: b'g' };
+        assert_eq!(err.context("0123g567", 2).to_string(), "...23[g]56...");
+        assert_eq!(err.context("0123g567", 3).to_string(), "...123[g]567");
+        assert_eq!(err.context("0123g567", 0).to_string(), "...[g]...");
+        assert_eq!(err.context("0123g", 9).to_string(), "0123[g]");
+
+        let err = InvalidCharError { pos: 0, invalid: b'g' };
+        assert_eq!(err.context("g123", 2).to_string(), "[g]12...");
+
+        // Multi-byte chars are not split, on either side of the invalid char.
+        let err = InvalidCharError { pos: 4, invalid: 171 };
+        assert_eq!(err.context("é0«00", 1).to_string(), "...0[«]0...");
+        assert_eq!(err.context("é0«00", 2).to_string(), "é0[«]00");
+
+        // Positions past the end of the input are clamped.
+        let err = InvalidCharError { pos: 10, invalid: b'g' };
+        assert_eq!(err.context("0123", 2).to_string(), "...23[]");
+    }
+
     #[test]
     fn hex_error_position() {
         use crate::error::InvalidCharError;
@@ -105,6 +411,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hex_to_array_error_position() {
+        use crate::error::InvalidCharError;
+
+        // Positions are counted in the input string, for the last byte of an array of length `N`
+        // the high nibble is at `(N - 1) * 2` and the low nibble at `(N - 1) * 2 + 1`.
+        assert_eq!(
+            <[u8; 4]>::from_hex("deadbegf"),
+            Err(InvalidCharError { pos: 6, invalid: b'g' }.into())
+        );
+        assert_eq!(
+            <[u8; 4]>::from_hex("deadbefg"),
+            Err(InvalidCharError { pos: 7, invalid: b'g' }.into())
+        );
+        assert_eq!(
+            <[u8; 1]>::from_hex("gf"),
+            Err(InvalidCharError { pos: 0, invalid: b'g' }.into())
+        );
+        assert_eq!(
+            <[u8; 1]>::from_hex("fg"),
+            Err(InvalidCharError { pos: 1, invalid: b'g' }.into())
+        );
+        // The first invalid char is reported.
+        assert_eq!(
+            <[u8; 1]>::from_hex("gh"),
+            Err(InvalidCharError { pos: 0, invalid: b'g' }.into())
+        );
+
+        // Length is checked before chars.
+        assert_eq!(<[u8; 1]>::from_hex(""), Err(InvalidLengthError::new(0, 2).into()));
+        assert_eq!(<[u8; 1]>::from_hex("g"), Err(InvalidLengthError::new(1, 2).into()));
+        assert_eq!(<[u8; 0]>::from_hex(""), Ok([]));
+        assert_eq!(<[u8; 0]>::from_hex("gg"), Err(InvalidLengthError::new(2, 0).into()));
+    }
+
+    #[test]
+    fn hex_to_array_tuple() {
+        use crate::error::InvalidCharError;
+
+        let got = <([u8; 2], [u8; 1])>::from_hex("deadbe").unwrap();
+        assert_eq!(got, ([0xde, 0xad], [0xbe]));
+        let got = <([u8; 1], [u8; 0], [u8; 2])>::from_hex("deadbe").unwrap();
+        assert_eq!(got, ([0xde], [], [0xad, 0xbe]));
+
+        assert_eq!(
+            <([u8; 2], [u8; 1])>::from_hex("deadbeef"),
+            Err(InvalidLengthError::new(8, 6).into())
+        );
+        // Positions are relative to the whole input.
+        assert_eq!(
+            <([u8; 2], [u8; 2])>::from_hex("deadbegf"),
+            Err(InvalidCharError { pos: 6, invalid: b'g' }.into())
+        );
+        // Multi-byte chars don't panic at the field boundary.
+        assert_eq!(
+            <([u8; 1], [u8; 1])>::from_hex("0«0"),
+            Err(InvalidCharError { pos: 1, invalid: 194 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn odd_length_error_unpaired_pos() {
+        let err = match Vec::<u8>::from_hex("deadb") {
+            Err(HexToBytesError::OddLengthString(e)) => e,
+            _ => panic!("expected odd length error"),
+        };
+        assert_eq!(err.length(), 5);
+        assert_eq!(err.unpaired_pos(), 4);
+        assert_eq!(
+            err.to_string(),
+            "odd hex string length 5, the character at position 4 has no pair"
+        );
+
+        // Prefixed decoding reports the position in the original input.
+        let err = match Vec::<u8>::from_hex_prefixed("0xdea") {
+            Err(HexToBytesError::OddLengthString(e)) => e,
+            _ => panic!("expected odd length error"),
+        };
+        assert_eq!(err.unpaired_pos(), 4);
+        assert_eq!(
+            err.to_string(),
+            "odd hex string length 5, the character at position 4 has no pair"
+        );
+    }
+
+    #[test]
+    fn invalid_length_error_accessors() {
+        let err = InvalidLengthError::new(11, 8);
+        assert_eq!(err.invalid_length(), 11);
+        assert_eq!(err.expected_length(), 8);
+        assert!(err.is_too_long());
+        assert!(!err.is_too_short());
+        assert_eq!(err.difference(), 3);
+
+        let err = match <[u8; 4]>::from_hex("abcd") {
+            Err(HexToArrayError::InvalidLength(e)) => e,
+            _ => panic!("expected length error"),
+        };
+        assert!(!err.is_too_long());
+        assert!(err.is_too_short());
+        assert_eq!(err.difference(), -4);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn invalid_length_error_display() {
+        let err = InvalidLengthError::new(6, 8);
+        assert_eq!(err.expected_bytes(), 4);
+        assert_eq!(
+            err.to_string(),
+            "invalid hex string length 6 (expected 8 characters for 4 bytes)"
+        );
+
+        // The prefix is counted in the lengths but not in the bytes.
+        let err = match crate::decode::to_array_prefixed::<2>("0xdeadbe") {
+            Err(HexToArrayError::InvalidLength(e)) => e,
+            _ => panic!("expected length error"),
+        };
+        assert_eq!(err.expected_bytes(), 2);
+        assert_eq!(
+            err.to_string(),
+            "invalid hex string length 8 (expected 6 characters for 2 bytes)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn error_kind() {
+        use crate::error::ErrorKind;
+
+        assert_eq!(Vec::<u8>::from_hex("abg0").unwrap_err().kind(), ErrorKind::InvalidChar);
+        assert_eq!(Vec::<u8>::from_hex("abc").unwrap_err().kind(), ErrorKind::OddLength);
+        assert_eq!(<[u8; 2]>::from_hex("abg0").unwrap_err().kind(), ErrorKind::InvalidChar);
+        assert_eq!(<[u8; 2]>::from_hex("abc").unwrap_err().kind(), ErrorKind::InvalidLength);
+    }
+
+    #[test]
+    fn hex_to_array_error_into_bytes_error() {
+        use crate::error::InvalidCharError;
+
+        let err = <[u8; 2]>::from_hex("abYd").unwrap_err();
+        assert_eq!(err.into_bytes_error(), Some(InvalidCharError { pos: 2, invalid: b'Y' }.into()));
+        let err = <[u8; 2]>::from_hex("abc").unwrap_err();
+        assert_eq!(err.into_bytes_error(), None);
+    }
+
     #[test]
     fn hex_to_array() {
         let len_sixteen = "0123456789abcdef";
@@ -114,10 +567,147 @@ mod tests {
     #[test]
     fn hex_to_array_error() {
         let len_sixteen = "0123456789abcdef";
+        assert_eq!(<[u8; 4]>::from_hex(len_sixteen), Err(InvalidLengthError::new(16, 8).into()))
+    }
+
+    #[test]
+    fn hex_to_array_length_checked_first() {
+        // Too short and too long, the invalid characters are never reached.
+        assert_eq!(<[u8; 4]>::from_hex("xxxxxx"), Err(InvalidLengthError::new(6, 8).into()));
+        assert_eq!(<[u8; 4]>::from_hex("deadbeefxx"), Err(InvalidLengthError::new(10, 8).into()));
+        assert_eq!(
+            <[u8; 4]>::from_hex("deadbeeg"),
+            Err(crate::error::InvalidCharError { invalid: b'g', pos: 7 }.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_to_boxed_slice() {
+        use crate::error::OddLengthStringError;
+
+        let got = Box::<[u8]>::from_hex("deadbeef").unwrap();
+        assert_eq!(&*got, &[0xde, 0xad, 0xbe, 0xef]);
+        assert!(Box::<[u8]>::from_hex("").unwrap().is_empty());
+        assert_eq!(Box::<[u8]>::from_hex("abc"), Err(OddLengthStringError { len: 3 }.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn hex_to_bytes_crate_types() {
+        use crate::error::OddLengthStringError;
+
+        let got = bytes::Bytes::from_hex("deadbeef").unwrap();
+        assert_eq!(&got[..], &[0xde, 0xad, 0xbe, 0xef]);
+        let got = bytes::BytesMut::from_hex("DEAD").unwrap();
+        assert_eq!(&got[..], &[0xde, 0xad]);
+        assert!(bytes::Bytes::from_hex("").unwrap().is_empty());
+        assert_eq!(bytes::Bytes::from_hex("abc"), Err(OddLengthStringError { len: 3 }.into()));
+    }
+
+    #[test]
+    fn hex_to_uint() {
+        assert_eq!(u8::from_hex("ab"), Ok(0xab));
+        assert_eq!(u16::from_hex("0xabcd"), Ok(0xabcd));
+        assert_eq!(u32::from_hex("0Xdeadbeef"), Ok(0xdeadbeef));
+        assert_eq!(u64::from_hex("0123456789abcdef"), Ok(0x0123_4567_89ab_cdef));
+        assert_eq!(
+            u128::from_hex("0x0123456789abcdef0123456789ABCDEF"),
+            Ok(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef)
+        );
+    }
+
+    #[test]
+    fn hex_to_int() {
+        assert_eq!(i8::from_hex("ff"), Ok(-1));
+        assert_eq!(i16::from_hex("0x8000"), Ok(i16::MIN));
+        assert_eq!(i32::from_hex("7fffffff"), Ok(i32::MAX));
+        assert_eq!(i64::from_hex("fffffffffffffffe"), Ok(-2));
+        assert_eq!(i128::from_hex("0x00000000000000000000000000000001"), Ok(1));
+    }
+
+    #[test]
+    fn from_hex_prefixed() {
+        use crate::error::{InvalidCharError, OddLengthStringError};
+
+        assert_eq!(<[u8; 2]>::from_hex_prefixed("0xdead"), Ok([0xde, 0xad]));
+        assert_eq!(<[u8; 2]>::from_hex_prefixed("dead"), Ok([0xde, 0xad]));
+        assert_eq!(
+            <[u8; 2]>::from_hex_prefixed("0xdeadbe"),
+            Err(InvalidLengthError { invalid: 8, expected: 6, bytes: 2 }.into())
+        );
+        assert_eq!(
+            <([u8; 1], [u8; 1])>::from_hex_prefixed("0Xdeag"),
+            Err(InvalidCharError { invalid: b'g', pos: 5 }.into())
+        );
+        assert_eq!(u16::from_hex_prefixed("0xdead"), Ok(0xdead));
+        assert_eq!(Vec::from_hex_prefixed("0x"), Ok(vec![]));
+        assert_eq!(
+            Box::<[u8]>::from_hex_prefixed("0xdea"),
+            Err(OddLengthStringError { len: 5 }.into())
+        );
         assert_eq!(
-            <[u8; 4]>::from_hex(len_sixteen),
-            Err(InvalidLengthError { invalid: 16, expected: 8 }.into())
-        )
+            crate::HexArray::<2>::from_hex_prefixed("0x0xde"),
+            Err(InvalidCharError { invalid: b'x', pos: 3 }.into())
+        );
+    }
+
+    #[test]
+    fn hex_to_int_error() {
+        use crate::error::InvalidCharError;
+
+        assert_eq!(u16::from_hex("abc"), Err(InvalidLengthError::new(3, 4).into()));
+        assert_eq!(
+            u16::from_hex("0xab"),
+            Err(InvalidLengthError { invalid: 4, expected: 6, bytes: 2 }.into())
+        );
+        assert_eq!(i8::from_hex("0g"), Err(InvalidCharError { invalid: b'g', pos: 1 }.into()));
+        assert_eq!(i8::from_hex("0x0g"), Err(InvalidCharError { invalid: b'g', pos: 3 }.into()));
+        assert_eq!(u16::from_hex("0Xabcg"), Err(InvalidCharError { invalid: b'g', pos: 5 }.into()));
+    }
+
+    #[test]
+    fn parse_traits() {
+        use core::marker::PhantomData;
+
+        use crate::error::InvalidCharError;
+
+        struct Forward([u8; 2]);
+
+        impl From<[u8; 2]> for Forward {
+            fn from(bytes: [u8; 2]) -> Self { Forward(bytes) }
+        }
+
+        impl_parse_traits! {
+            impl parse_traits for Forward {
+                const LENGTH: usize = 2;
+            }
+        }
+
+        struct Backward<T>([u8; 2], PhantomData<T>);
+
+        impl<T: Clone> From<[u8; 2]> for Backward<T> {
+            fn from(bytes: [u8; 2]) -> Self { Backward(bytes, PhantomData) }
+        }
+
+        impl_parse_traits! {
+            #[display_backward(true)]
+            impl<T: Clone> parse_traits for Backward<T> {
+                const LENGTH: usize = 2;
+            }
+        }
+
+        assert_eq!("1234".parse::<Forward>().unwrap().0, [0x12, 0x34]);
+        assert_eq!("1234".parse::<Backward<u32>>().unwrap().0, [0x34, 0x12]);
+        assert!(matches!(
+            "12".parse::<Forward>(),
+            Err(HexToArrayError::InvalidLength(e)) if e == InvalidLengthError::new(2, 4)
+        ));
+        // Positions refer to the input string.
+        assert!(matches!(
+            "123g".parse::<Backward<u32>>(),
+            Err(HexToArrayError::InvalidChar(e)) if e == InvalidCharError { invalid: b'g', pos: 3 }
+        ));
     }
 
     #[test]
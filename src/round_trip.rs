@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Round-trip helpers for fuzzing and property testing.
+//!
+//! These functions run data through this crate's encoder and decoder so that downstream fuzz
+//! targets and property tests can assert round-trip invariants against this implementation
+//! instead of maintaining their own reference decoders.
+//!
+//! # Examples
+//!
+//! ```
+//! use hex_conservative::round_trip;
+//!
+//! let data = [0xde, 0xad, 0xbe, 0xef];
+//! assert_eq!(round_trip::encode_then_decode(&data), data);
+//!
+//! assert_eq!(round_trip::decode_then_encode("DEADbeef").as_deref(), Some("deadbeef"));
+//! assert_eq!(round_trip::decode_then_encode("deadbee"), None);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::display::DisplayHex;
+use crate::parse::FromHex;
+
+/// Encodes `bytes` as lower-case hex and decodes the result again.
+///
+/// # Panics
+///
+/// If the decoder rejects the output of the encoder, this is always a bug in this crate.
+pub fn encode_then_decode(bytes: &[u8]) -> Vec<u8> {
+    let hex = bytes.to_lower_hex_string();
+    Vec::from_hex(&hex).expect("encoder output is always valid hex")
+}
+
+/// Decodes `hex` and encodes the result again as lower-case hex.
+///
+/// Returns `None` if `hex` is not valid hex. Since case is not preserved by decoding, the output
+/// is equal to `hex.to_ascii_lowercase()` for all valid input.
+pub fn decode_then_encode(hex: &str) -> Option<String> {
+    Vec::from_hex(hex).ok().map(|bytes| bytes.to_lower_hex_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(encode_then_decode(&bytes), bytes);
+        assert_eq!(encode_then_decode(&[]), Vec::<u8>::new());
+
+        assert_eq!(decode_then_encode("").as_deref(), Some(""));
+        assert_eq!(decode_then_encode("0aBcDeF9").as_deref(), Some("0abcdef9"));
+        assert_eq!(decode_then_encode("abc"), None);
+        assert_eq!(decode_then_encode("zz"), None);
+    }
+}
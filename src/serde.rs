@@ -20,10 +20,15 @@
 use core::fmt;
 use core::marker::PhantomData;
 
+#[cfg(feature = "alloc")]
+use serde::de::SeqAccess;
 use serde::de::{Error, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use crate::alloc::vec::Vec;
 use crate::prelude::*;
+use crate::Case;
 
 /// Serializes `data` as a hex string using lowercase characters.
 ///
@@ -46,12 +51,7 @@ where
     S: Serializer,
     T: Serialize + DisplayHex,
 {
-    // Don't do anything special when not human readable.
-    if !serializer.is_human_readable() {
-        serde::Serialize::serialize(&data, serializer)
-    } else {
-        serializer.collect_str(&format_args!("{:x}", data.as_hex()))
-    }
+    serialize_with_case(data, Case::Lower, serializer)
 }
 
 /// Serializes `data` as hex string using uppercase characters.
@@ -59,6 +59,20 @@ where
 /// We only serialize as hex if the serializer is human readable, if not we call through to the
 /// `Serialize` implementation for `data`.
 pub fn serialize_upper<S, T>(data: T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + DisplayHex,
+{
+    serialize_with_case(data, Case::Upper, serializer)
+}
+
+/// Serializes `data` as a hex string using characters of the given `case`.
+///
+/// Useful when the case is only known at runtime, e.g. from a configuration flag.
+///
+/// We only serialize as hex if the serializer is human readable, if not we call through to the
+/// `Serialize` implementation for `data`.
+pub fn serialize_with_case<S, T>(data: T, case: Case, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
     T: Serialize + DisplayHex,
@@ -67,7 +81,10 @@ where
     if !serializer.is_human_readable() {
         serde::Serialize::serialize(&data, serializer)
     } else {
-        serializer.collect_str(&format_args!("{:X}", data.as_hex()))
+        match case {
+            Case::Lower => serializer.collect_str(&format_args!("{:x}", data.as_hex())),
+            Case::Upper => serializer.collect_str(&format_args!("{:X}", data.as_hex())),
+        }
     }
 }
 
@@ -107,9 +124,82 @@ impl serde::Serialize for SerializeBytesAsHexUpper<'_> {
     }
 }
 
+/// Owned bytes which de/serialize as a hex string.
+///
+/// This can be used as a field type instead of annotating each field with
+/// `#[serde(with = "hex::serde")]`, the encoding is the same: lowercase hex when the format is
+/// human readable and the `Vec<u8>` implementation otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hex_conservative::serde::HexBytes;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Foo {
+///     bar: HexBytes,
+/// }
+///
+/// let foo = Foo { bar: HexBytes(vec![0xde, 0xad]) };
+/// assert_eq!(serde_json::to_string(&foo).unwrap(), r#"{"bar":"dead"}"#);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexBytes(pub Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl core::ops::Deref for HexBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] { &self.0 }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for HexBytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<u8>> for HexBytes {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self { Self(bytes) }
+}
+
+#[cfg(feature = "alloc")]
+impl From<HexBytes> for Vec<u8> {
+    #[inline]
+    fn from(bytes: HexBytes) -> Self { bytes.0 }
+}
+
+#[cfg(feature = "alloc")]
+impl Serialize for HexBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_lower(self.0.as_slice(), serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for HexBytes {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        // Not using `deserialize` since `Vec<u8>: Deserialize` requires the `alloc` feature of
+        // `serde`. A sequence of bytes is what `Vec<u8>` deserializes from.
+        if !d.is_human_readable() {
+            d.deserialize_seq(FlexibleVisitor(PhantomData)).map(Self)
+        } else {
+            d.deserialize_str(HexVisitor { strip_prefix: false, _marker: PhantomData }).map(Self)
+        }
+    }
+}
+
 /// Deserializes a hex string into raw bytes.
 ///
-/// Allows upper, lower, and mixed case characters (e.g. `a5b3c1`, `A5B3C1` and `A5b3C1`).
+/// Allows upper, lower, and mixed case characters (e.g. `a5b3c1`, `A5B3C1` and `A5b3C1`). A `0x`
+/// prefix is rejected, use [`deserialize_prefixed`] to accept it.
 ///
 /// We only deserialize from hex if the serializer is human readable, if not we call through to the
 /// `Deserialize` implementation for `T`.
@@ -118,27 +208,601 @@ where
     D: Deserializer<'de>,
     T: Deserialize<'de> + FromHex,
 {
-    struct HexVisitor<T>(PhantomData<T>);
+    // Don't do anything special when not human readable.
+    if !d.is_human_readable() {
+        serde::Deserialize::deserialize(d)
+    } else {
+        d.deserialize_str(HexVisitor { strip_prefix: false, _marker: PhantomData })
+    }
+}
+
+/// Deserializes a hex string with an optional `0x` or `0X` prefix into raw bytes.
+///
+/// This is the same as [`deserialize`] except that a single leading prefix is stripped before
+/// decoding, which is useful when the hex is formatted like an integer (e.g. `"0xdeadbeef"`).
+///
+/// We only deserialize from hex if the serializer is human readable, if not we call through to the
+/// `Deserialize` implementation for `T`.
+pub fn deserialize_prefixed<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromHex,
+{
+    // Don't do anything special when not human readable.
+    if !d.is_human_readable() {
+        serde::Deserialize::deserialize(d)
+    } else {
+        d.deserialize_str(HexVisitor { strip_prefix: true, _marker: PhantomData })
+    }
+}
+
+/// Deserializes either a hex string or raw bytes.
+///
+/// A string is decoded as hex same as [`deserialize`], while a byte string or a sequence of bytes
+/// is taken verbatim. This is useful for tolerant parsers ingesting data from producers that
+/// don't agree on the representation.
+///
+/// This uses `deserialize_any` so it only works with self-describing formats such as JSON or
+/// CBOR, regardless of whether they are human readable.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hex_conservative as hex;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Foo {
+///     #[serde(deserialize_with = "hex::serde::deserialize_flexible")]
+///     bar: Vec<u8>,
+/// }
+///
+/// let a: Foo = serde_json::from_str(r#"{"bar":"dead"}"#).unwrap();
+/// let b: Foo = serde_json::from_str(r#"{"bar":[222,173]}"#).unwrap();
+/// assert_eq!(a.bar, b.bar);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn deserialize_flexible<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromHex + TryFrom<Vec<u8>>,
+{
+    d.deserialize_any(FlexibleVisitor(PhantomData))
+}
+
+#[cfg(feature = "alloc")]
+struct FlexibleVisitor<T>(PhantomData<T>);
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Visitor<'de> for FlexibleVisitor<T>
+where
+    T: FromHex + TryFrom<Vec<u8>>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ASCII hex string or bytes")
+    }
+
+    fn visit_str<E: Error>(self, data: &str) -> Result<Self::Value, E> {
+        FromHex::from_hex(data).map_err(Error::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, data: &[u8]) -> Result<Self::Value, E> {
+        self.convert(data.to_vec())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            data.push(byte);
+        }
+        self.convert(data)
+    }
+}
 
-    impl<T> Visitor<'_> for HexVisitor<T>
+#[cfg(feature = "alloc")]
+impl<T: TryFrom<Vec<u8>>> FlexibleVisitor<T> {
+    /// Converts raw bytes, a wrong length for `T` is reported as an invalid length error.
+    fn convert<E: Error>(self, data: Vec<u8>) -> Result<T, E>
     where
-        T: FromHex,
+        Self: for<'de> Visitor<'de>,
     {
-        type Value = T;
+        let len = data.len();
+        T::try_from(data).map_err(|_| Error::invalid_length(len, &self))
+    }
+}
+
+struct HexVisitor<T> {
+    strip_prefix: bool,
+    _marker: PhantomData<T>,
+}
 
-        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            f.write_str("an ASCII hex string")
+impl<T> Visitor<'_> for HexVisitor<T>
+where
+    T: FromHex,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ASCII hex string")
+    }
+
+    fn visit_str<E: Error>(self, data: &str) -> Result<Self::Value, E> {
+        if self.strip_prefix {
+            T::from_hex_prefixed(data).map_err(Error::custom)
+        } else {
+            T::from_hex(data).map_err(Error::custom)
         }
+    }
+}
 
-        fn visit_str<E: Error>(self, data: &str) -> Result<Self::Value, E> {
-            FromHex::from_hex(data).map_err(Error::custom)
+/// Hex encoding with `serde` for fixed size byte arrays of any length.
+///
+/// `serde` only implements `Serialize` and `Deserialize` for arrays up to 32 bytes, this module
+/// supports `[u8; N]` fields of any length. The array is de/serialized as hex when the
+/// serializer is human readable and as a tuple of bytes (same as `serde` arrays) otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hex_conservative as hex;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Foo {
+///     #[serde(with = "hex::serde::hex_array")]
+///     bar: [u8; 64],
+/// }
+/// # }
+/// ```
+pub mod hex_array {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserializer, Serializer};
+
+    use crate::prelude::*;
+    use crate::HexToArrayError;
+
+    /// Serializes `data` as a hex string using lowercase characters.
+    ///
+    /// We only serialize as hex if the serializer is human readable, if not we serialize `data`
+    /// as a tuple of bytes.
+    pub fn serialize<S, const N: usize>(data: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !serializer.is_human_readable() {
+            let mut tuple = serializer.serialize_tuple(N)?;
+            for byte in data {
+                tuple.serialize_element(byte)?;
+            }
+            tuple.end()
+        } else {
+            serializer.collect_str(&format_args!("{:x}", data.as_hex()))
         }
     }
 
-    // Don't do anything special when not human readable.
-    if !d.is_human_readable() {
-        serde::Deserialize::deserialize(d)
-    } else {
-        d.deserialize_map(HexVisitor(PhantomData))
+    /// Deserializes a hex string of exactly `N * 2` characters into a byte array.
+    ///
+    /// Allows upper, lower, and mixed case characters (e.g. `a5b3c1`, `A5B3C1` and `A5b3C1`). If
+    /// the string has the wrong length the error message includes both the expected and the
+    /// received length.
+    ///
+    /// We only deserialize from hex if the deserializer is human readable, if not we deserialize
+    /// a tuple of bytes.
+    pub fn deserialize<'de, D, const N: usize>(d: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+        impl<'de, const N: usize> Visitor<'de> for ArrayVisitor<N> {
+            type Value = [u8; N];
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an ASCII hex string of length {} or {} bytes", N * 2, N)
+            }
+
+            fn visit_str<E: Error>(self, data: &str) -> Result<Self::Value, E> {
+                FromHex::from_hex(data).map_err(|e| match e {
+                    // The length error alone has the more useful message.
+                    HexToArrayError::InvalidLength(e) => E::custom(e),
+                    e => E::custom(e),
+                })
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut ret = [0u8; N];
+                for (i, byte) in ret.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| Error::invalid_length(i, &self))?;
+                }
+                Ok(ret)
+            }
+        }
+
+        if !d.is_human_readable() {
+            d.deserialize_tuple(N, ArrayVisitor(PhantomData))
+        } else {
+            d.deserialize_str(ArrayVisitor(PhantomData))
+        }
+    }
+}
+
+/// Hex encoding with `serde` for optional fields.
+///
+/// `None` is de/serialized as the serializer's none value, the contained value of `Some` is
+/// de/serialized same as with the functions in the parent module, including the human readable
+/// split. Use together with `#[serde(default)]` to also accept a missing field.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use hex_conservative as hex;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Foo {
+///     #[serde(with = "hex::serde::opt", default)]
+///     bar: Option<[u8; 32]>,
+/// }
+/// # }
+/// ```
+pub mod opt {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::Visitor;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::prelude::*;
+
+    /// Serializes the contained value as a hex string using lowercase characters.
+    ///
+    /// We only serialize as hex if the serializer is human readable, if not we call through to the
+    /// `Serialize` implementation for the contained value.
+    pub fn serialize<S, T>(data: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        for<'a> &'a T: Serialize + DisplayHex,
+    {
+        /// Serializes the contained value using the parent module.
+        struct Hex<'a, T>(&'a T);
+
+        impl<T> Serialize for Hex<'_, T>
+        where
+            for<'a> &'a T: Serialize + DisplayHex,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        match data {
+            Some(data) => serializer.serialize_some(&Hex(data)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional hex string into raw bytes.
+    ///
+    /// The contained value is deserialized same as with [`super::deserialize`].
+    pub fn deserialize<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + FromHex,
+    {
+        struct OptVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for OptVisitor<T>
+        where
+            T: Deserialize<'de> + FromHex,
+        {
+            type Value = Option<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an optional ASCII hex string")
+            }
+
+            fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> { Ok(None) }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> { Ok(None) }
+
+            fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+                super::deserialize(d).map(Some)
+            }
+        }
+
+        d.deserialize_option(OptVisitor(PhantomData))
+    }
+}
+
+/// Hex encoding with `serde` for the values of maps with byte vector values.
+///
+/// Keys are de/serialized as usual, values are de/serialized same as with the functions in the
+/// parent module, including the human readable split. This works with any map that can be
+/// iterated by reference and collected from pairs, e.g. `HashMap<K, Vec<u8>>` and
+/// `BTreeMap<K, Vec<u8>>`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+///
+/// use hex_conservative as hex;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "hex::serde::map_values")]
+///     keys: HashMap<String, Vec<u8>>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"keys":{"alice":"dead"}}"#).unwrap();
+/// assert_eq!(config.keys["alice"], [0xde, 0xad]);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub mod map_values {
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    use serde::de::{MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::HexBytes;
+    #[cfg(not(feature = "std"))]
+    use crate::alloc::vec::Vec;
+
+    /// Serializes the values of `map` as hex strings using lowercase characters.
+    ///
+    /// We only serialize as hex if the serializer is human readable, if not we call through to the
+    /// `Serialize` implementation for the values.
+    pub fn serialize<'a, S, M, K>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        &'a M: IntoIterator<Item = (&'a K, &'a Vec<u8>)>,
+        K: Serialize + 'a,
+    {
+        /// Serializes a value using the parent module.
+        struct Hex<'a>(&'a [u8]);
+
+        impl Serialize for Hex<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        serializer.collect_map(map.into_iter().map(|(key, value)| (key, Hex(value))))
+    }
+
+    /// Deserializes a map with hex string values into a map with raw byte values.
+    ///
+    /// The values are deserialized same as with [`super::deserialize`].
+    pub fn deserialize<'de, D, M, K>(d: D) -> Result<M, D::Error>
+    where
+        D: Deserializer<'de>,
+        M: Default + Extend<(K, Vec<u8>)>,
+        K: Deserialize<'de>,
+    {
+        struct MapVisitor<M, K>(PhantomData<(M, K)>);
+
+        impl<'de, M, K> Visitor<'de> for MapVisitor<M, K>
+        where
+            M: Default + Extend<(K, Vec<u8>)>,
+            K: Deserialize<'de>,
+        {
+            type Value = M;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with ASCII hex string values")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+                let mut map = M::default();
+                // `HexBytes` since `Vec<u8>: Deserialize` requires the `alloc` feature of `serde`.
+                while let Some((key, HexBytes(value))) = access.next_entry::<K, HexBytes>()? {
+                    map.extend(core::iter::once((key, value)));
+                }
+                Ok(map)
+            }
+        }
+
+        d.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Foo {
+        #[serde(with = "super::hex_array")]
+        small: [u8; 4],
+        #[serde(with = "super::hex_array")]
+        large: [u8; 33],
+    }
+
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct Strict {
+        #[serde(deserialize_with = "super::deserialize")]
+        bytes: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct Prefixed {
+        #[serde(deserialize_with = "super::deserialize_prefixed")]
+        bytes: Vec<u8>,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct Flexible {
+        #[serde(deserialize_with = "super::deserialize_flexible")]
+        bytes: Vec<u8>,
+        #[serde(deserialize_with = "super::deserialize_flexible")]
+        array: [u8; 2],
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Optional {
+        #[serde(with = "super::opt", default)]
+        bytes: Option<Vec<u8>>,
+        #[serde(with = "super::opt", default)]
+        array: Option<[u8; 2]>,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Owned {
+        bytes: super::HexBytes,
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Keyed {
+        #[serde(with = "super::map_values")]
+        hashed: std::collections::HashMap<String, Vec<u8>>,
+        #[serde(with = "super::map_values")]
+        ordered: std::collections::BTreeMap<u32, Vec<u8>>,
+    }
+
+    struct WithCase {
+        bytes: Vec<u8>,
+        case: crate::Case,
+    }
+
+    impl Serialize for WithCase {
+        fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            super::serialize_with_case(self.bytes.as_slice(), self.case, s)
+        }
+    }
+
+    #[test]
+    fn serialize_with_case() {
+        let bytes = vec![0xde, 0xad, 0xbe, 0xef];
+        let lower = WithCase { bytes: bytes.clone(), case: crate::Case::Lower };
+        assert_eq!(serde_json::to_string(&lower).unwrap(), "\"deadbeef\"");
+        let upper = WithCase { bytes, case: crate::Case::Upper };
+        assert_eq!(serde_json::to_string(&upper).unwrap(), "\"DEADBEEF\"");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_bytes() {
+        let owned = Owned { bytes: super::HexBytes(vec![0xde, 0xad, 0xbe, 0xef]) };
+        let json = serde_json::to_string(&owned).unwrap();
+        assert_eq!(json, r#"{"bytes":"deadbeef"}"#);
+        assert_eq!(serde_json::from_str::<Owned>(&json).unwrap(), owned);
+        assert_eq!(serde_json::from_str::<Owned>(r#"{"bytes":"DEADBEEF"}"#).unwrap(), owned);
+        assert!(serde_json::from_str::<Owned>(r#"{"bytes":"deadbee"}"#).is_err());
+        assert_eq!(&owned.bytes[..2], [0xde, 0xad]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn map_values() {
+        let keyed = Keyed {
+            hashed: [("a".to_owned(), vec![0xde, 0xad])].into_iter().collect(),
+            ordered: [(2, vec![0xbe, 0xef]), (1, vec![])].into_iter().collect(),
+        };
+        let json = serde_json::to_string(&keyed).unwrap();
+        assert_eq!(json, r#"{"hashed":{"a":"dead"},"ordered":{"1":"","2":"beef"}}"#);
+        assert_eq!(serde_json::from_str::<Keyed>(&json).unwrap(), keyed);
+
+        let bad = r#"{"hashed":{"a":"dea"},"ordered":{}}"#;
+        assert!(serde_json::from_str::<Keyed>(bad).is_err());
+    }
+
+    #[test]
+    fn deserialize_strict() {
+        let got = serde_json::from_str::<Strict>(r#"{"bytes":"deadBEEF"}"#).unwrap();
+        assert_eq!(got, Strict { bytes: vec![0xde, 0xad, 0xbe, 0xef] });
+        assert!(serde_json::from_str::<Strict>(r#"{"bytes":"0xdeadbeef"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn deserialize_hex_or_bytes() {
+        let want = Flexible { bytes: vec![0xde, 0xad], array: [0xbe, 0xef] };
+        let got = serde_json::from_str::<Flexible>(r#"{"bytes":"dead","array":"BEEF"}"#).unwrap();
+        assert_eq!(got, want);
+        let got = serde_json::from_str::<Flexible>(r#"{"bytes":[222,173],"array":[190,239]}"#);
+        assert_eq!(got.unwrap(), want);
+        let got = serde_json::from_str::<Flexible>(r#"{"bytes":"dead","array":[190,239]}"#);
+        assert_eq!(got.unwrap(), want);
+
+        assert!(serde_json::from_str::<Flexible>(r#"{"bytes":"dea","array":"beef"}"#).is_err());
+        assert!(serde_json::from_str::<Flexible>(r#"{"bytes":"","array":[190]}"#).is_err());
+        assert!(serde_json::from_str::<Flexible>(r#"{"bytes":[256],"array":"beef"}"#).is_err());
+        assert!(serde_json::from_str::<Flexible>(r#"{"bytes":1,"array":"beef"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn optional_roundtrip() {
+        let some = Optional { bytes: Some(vec![0xde, 0xad]), array: Some([0xbe, 0xef]) };
+        let json = serde_json::to_string(&some).unwrap();
+        assert_eq!(json, r#"{"bytes":"dead","array":"beef"}"#);
+        assert_eq!(serde_json::from_str::<Optional>(&json).unwrap(), some);
+
+        let none = Optional { bytes: None, array: None };
+        let json = serde_json::to_string(&none).unwrap();
+        assert_eq!(json, r#"{"bytes":null,"array":null}"#);
+        assert_eq!(serde_json::from_str::<Optional>(&json).unwrap(), none);
+        assert_eq!(serde_json::from_str::<Optional>("{}").unwrap(), none);
+
+        assert!(serde_json::from_str::<Optional>(r#"{"array":"beefee"}"#).is_err());
+    }
+
+    #[test]
+    fn deserialize_with_prefix() {
+        let want = Prefixed { bytes: vec![0xde, 0xad, 0xbe, 0xef] };
+        let got = serde_json::from_str::<Prefixed>(r#"{"bytes":"0xdeadbeef"}"#).unwrap();
+        assert_eq!(got, want);
+        let got = serde_json::from_str::<Prefixed>(r#"{"bytes":"0Xdeadbeef"}"#).unwrap();
+        assert_eq!(got, want);
+        let got = serde_json::from_str::<Prefixed>(r#"{"bytes":"deadbeef"}"#).unwrap();
+        assert_eq!(got, want);
+        assert!(serde_json::from_str::<Prefixed>(r#"{"bytes":"0x0xdeadbeef"}"#).is_err());
+    }
+
+    #[test]
+    fn hex_array_roundtrip() {
+        let foo = Foo { small: [0xde, 0xad, 0xbe, 0xef], large: [0xab; 33] };
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, format!("{{\"small\":\"deadbeef\",\"large\":\"{}\"}}", "ab".repeat(33)));
+        assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+    }
+
+    #[test]
+    fn hex_array_newtype() {
+        let a = crate::HexArray::from([0xde, 0xad, 0xbe, 0xef]);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "\"deadbeef\"");
+        assert_eq!(serde_json::from_str::<crate::HexArray<4>>(&json).unwrap(), a);
+    }
+
+    #[test]
+    fn hex_array_invalid_length() {
+        let json = format!("{{\"small\":\"deadbe\",\"large\":\"{}\"}}", "ab".repeat(33));
+        assert!(serde_json::from_str::<Foo>(&json).is_err());
+        let json = format!("{{\"small\":\"deadbeef\",\"large\":\"{}\"}}", "ab".repeat(34));
+        assert!(serde_json::from_str::<Foo>(&json).is_err());
+
+        let err = serde_json::from_str::<crate::HexArray<4>>("\"deadbe\"").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid hex string length 6 (expected 8 characters for 4 bytes)"));
     }
 }